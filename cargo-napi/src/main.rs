@@ -0,0 +1,453 @@
+extern crate napi_typegen;
+#[cfg_attr(test, macro_use)]
+extern crate serde_json;
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+
+use napi_typegen::TypeDefDir;
+use serde_json::Value;
+
+const USAGE: &str = "\
+Usage: cargo napi build [options] [<cargo build options>...]
+
+Builds the cdylib addon in the current directory into <name>.node, and
+writes index.d.ts and, unless there already is one, package.json next to it.
+
+Options:
+    -o, --out-dir <dir>  Write the files to <dir> instead of the current one
+        --name <name>    Name the addon <name> instead of after the package
+        --strip          Strip the debug symbols from the addon
+        --no-dts         Do not generate index.d.ts
+    -h, --help           Print this message";
+
+struct Options {
+    out_dir: PathBuf,
+    name: Option<String>,
+    strip: bool,
+    dts: bool,
+    cargo_args: Vec<OsString>,
+}
+
+// The library built for the addon, and the manifest of its package.
+struct Artifact {
+    path: PathBuf,
+    manifest_path: PathBuf,
+}
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+fn run() -> io::Result<()> {
+    let options = match parse_args(env::args_os().skip(1))? {
+        Some(options) => options,
+        None => {
+            println!("{}", USAGE);
+            return Ok(());
+        }
+    };
+
+    let type_defs = TypeDefDir::new()?;
+    let artifact = build(&options, &type_defs)?;
+    let package = package_metadata(&artifact.manifest_path)?;
+    let name = match options.name {
+        Some(ref name) => name.clone(),
+        None => package["name"].as_str().unwrap_or("addon").to_owned(),
+    };
+
+    fs::create_dir_all(&options.out_dir)?;
+
+    let addon = options.out_dir.join(format!("{}.node", name));
+    fs::copy(&artifact.path, &addon)?;
+    if options.strip {
+        strip(&addon)?;
+    }
+    eprintln!("Wrote {}", addon.display());
+
+    if options.dts {
+        let path = options.out_dir.join("index.d.ts");
+        fs::write(&path, type_defs.render()?)?;
+        eprintln!("Wrote {}", path.display());
+    }
+
+    // The package may have been edited by hand, so it is never overwritten.
+    let path = options.out_dir.join("package.json");
+    if !path.exists() {
+        fs::write(&path, package_json(&package, &name, options.dts))?;
+        eprintln!("Wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+// Returns `None` when the usage is asked for.
+fn parse_args<I>(args: I) -> io::Result<Option<Options>>
+where
+    I: IntoIterator<Item = OsString>,
+{
+    let mut args = args.into_iter().peekable();
+
+    // Cargo passes the name of the subcommand on to it.
+    if args.peek().is_some_and(|arg| arg == "napi") {
+        args.next();
+    }
+
+    match args.next() {
+        Some(ref arg) if arg == "build" => {}
+        Some(ref arg) if arg == "-h" || arg == "--help" => return Ok(None),
+        _ => return Err(invalid_args("expected the `build` command")),
+    }
+
+    let mut options = Options {
+        out_dir: PathBuf::from("."),
+        name: None,
+        strip: false,
+        dts: true,
+        cargo_args: Vec::new(),
+    };
+
+    while let Some(arg) = args.next() {
+        if arg == "-o" || arg == "--out-dir" {
+            options.out_dir = args
+                .next()
+                .map(PathBuf::from)
+                .ok_or_else(|| invalid_args("expected a directory"))?;
+        } else if arg == "--name" {
+            options.name = args
+                .next()
+                .and_then(|name| name.into_string().ok())
+                .map(Some)
+                .ok_or_else(|| invalid_args("expected a name"))?;
+        } else if arg == "--strip" {
+            options.strip = true;
+        } else if arg == "--no-dts" {
+            options.dts = false;
+        } else if arg == "-h" || arg == "--help" {
+            return Ok(None);
+        } else if arg == "--message-format"
+            || arg.to_str().is_some_and(|arg| {
+                arg.starts_with("--message-format=")
+            })
+        {
+            // The artifacts are found in the JSON messages of `cargo build`.
+            return Err(invalid_args(
+                "--message-format is set by `cargo napi build` itself",
+            ));
+        } else {
+            options.cargo_args.push(arg);
+        }
+    }
+
+    Ok(Some(options))
+}
+
+fn build(options: &Options, type_defs: &TypeDefDir) -> io::Result<Artifact> {
+    let mut command = cargo();
+    command
+        .args(["build", "--message-format=json-render-diagnostics"])
+        .args(&options.cargo_args)
+        .stdout(Stdio::piped());
+    if options.dts {
+        type_defs.apply(&mut command);
+    }
+
+    let mut child = command.spawn()?;
+    let mut artifacts = Vec::new();
+
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines() {
+            if let Ok(message) = serde_json::from_str(&line?) {
+                artifacts.extend(cdylib_artifact(&message));
+            }
+        }
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "cargo build failed ({})",
+            status,
+        )));
+    }
+
+    match artifacts.len() {
+        1 => Ok(artifacts.remove(0)),
+        0 => Err(io::Error::other(
+            "no cdylib was built, add `crate-type = [\"cdylib\"]` to the \
+             [lib] section of Cargo.toml",
+        )),
+        _ => Err(io::Error::other(
+            "several cdylibs were built, select one with --package",
+        )),
+    }
+}
+
+fn cdylib_artifact(message: &Value) -> Option<Artifact> {
+    if message["reason"] != "compiler-artifact" {
+        return None;
+    }
+
+    let crate_types = message["target"]["crate_types"].as_array()?;
+    if !crate_types.iter().any(|crate_type| crate_type == "cdylib") {
+        return None;
+    }
+
+    let path = message["filenames"]
+        .as_array()?
+        .iter()
+        .filter_map(Value::as_str)
+        .find(|path| {
+            path.ends_with(".so")
+                || path.ends_with(".dylib")
+                || path.ends_with(".dll")
+        })?;
+
+    Some(Artifact {
+        path: PathBuf::from(path),
+        manifest_path: PathBuf::from(message["manifest_path"].as_str()?),
+    })
+}
+
+fn package_metadata(manifest_path: &Path) -> io::Result<Value> {
+    let output = cargo()
+        .args(["metadata", "--no-deps", "--format-version", "1"])
+        .arg("--manifest-path")
+        .arg(manifest_path)
+        .stderr(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other("cargo metadata failed"));
+    }
+
+    let mut metadata = serde_json::from_slice::<Value>(&output.stdout)?;
+    let packages = match metadata["packages"].as_array_mut() {
+        Some(packages) => packages,
+        None => return Err(io::Error::other("unexpected cargo metadata")),
+    };
+    let manifest_path = manifest_path.to_str();
+
+    match packages
+        .iter()
+        .position(|package| package["manifest_path"].as_str() == manifest_path)
+    {
+        Some(idx) => Ok(packages.swap_remove(idx)),
+        None => Err(io::Error::other("the package of the addon not found")),
+    }
+}
+
+// A minimal `package.json` whose main module is the addon.
+fn package_json(package: &Value, name: &str, dts: bool) -> String {
+    let mut fields = vec![
+        ("name", Value::from(name)),
+        ("version", package["version"].clone()),
+    ];
+    for &key in &["description", "license"] {
+        if package[key].is_string() {
+            fields.push((key, package[key].clone()));
+        }
+    }
+    fields.push(("main", Value::from(format!("{}.node", name))));
+    if dts {
+        fields.push(("types", Value::from("index.d.ts")));
+    }
+
+    let fields = fields
+        .iter()
+        .map(|&(key, ref value)| format!("  \"{}\": {}", key, value))
+        .collect::<Vec<_>>();
+
+    format!("{{\n{}\n}}\n", fields.join(",\n"))
+}
+
+#[cfg(not(windows))]
+fn strip(path: &Path) -> io::Result<()> {
+    // The dynamic symbols that Node.js looks up are kept either way.
+    let flag = if cfg!(target_os = "macos") {
+        "-x"
+    } else {
+        "--strip-unneeded"
+    };
+
+    let status = Command::new("strip").arg(flag).arg(path).status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("strip failed ({})", status)))
+    }
+}
+
+// The debug symbols of DLLs are kept in separate `.pdb` files.
+#[cfg(windows)]
+fn strip(_: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+fn cargo() -> Command {
+    Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+}
+
+fn invalid_args(message: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("{}\n\n{}", message, USAGE),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsString;
+    use std::path::PathBuf;
+
+    use serde_json::Value;
+
+    use super::{cdylib_artifact, package_json, parse_args, Options};
+
+    fn parse(args: &[&str]) -> Option<Options> {
+        parse_args(args.iter().map(OsString::from)).unwrap()
+    }
+
+    fn parse_error(args: &[&str]) -> String {
+        match parse_args(args.iter().map(OsString::from)) {
+            Ok(_) => panic!("{:?} parsed", args),
+            Err(error) => error.to_string(),
+        }
+    }
+
+    #[test]
+    fn parses_options() {
+        let options =
+            parse(&["napi", "build", "-o", "dist", "--name", "x", "--strip"])
+                .unwrap();
+        assert_eq!(options.out_dir, PathBuf::from("dist"));
+        assert_eq!(options.name.as_deref(), Some("x"));
+        assert!(options.strip);
+        assert!(options.dts);
+        assert!(options.cargo_args.is_empty());
+
+        let options = parse(&["build", "--no-dts"]).unwrap();
+        assert_eq!(options.out_dir, PathBuf::from("."));
+        assert!(options.name.is_none());
+        assert!(!options.strip);
+        assert!(!options.dts);
+    }
+
+    #[test]
+    fn passes_unknown_options_on_to_cargo() {
+        let options =
+            parse(&["build", "--release", "-p", "addon", "--strip"]).unwrap();
+        assert_eq!(options.cargo_args, ["--release", "-p", "addon"]);
+    }
+
+    #[test]
+    fn prints_the_usage() {
+        assert!(parse(&["--help"]).is_none());
+        assert!(parse(&["napi", "-h"]).is_none());
+        assert!(parse(&["build", "--release", "--help"]).is_none());
+    }
+
+    #[test]
+    fn rejects_invalid_args() {
+        assert!(parse_error(&[]).starts_with("expected the `build` command"));
+        assert!(parse_error(&["test"])
+            .starts_with("expected the `build` command"));
+        assert!(parse_error(&["build", "--out-dir"])
+            .starts_with("expected a directory"));
+        assert!(parse_error(&["build", "--name"])
+            .starts_with("expected a name"));
+    }
+
+    #[test]
+    fn rejects_message_formats() {
+        for args in &[
+            &["build", "--message-format", "short"][..],
+            &["build", "--message-format=json"][..],
+        ] {
+            assert!(parse_error(args).starts_with(
+                "--message-format is set by `cargo napi build` itself"
+            ));
+        }
+    }
+
+    fn artifact_message(crate_types: &[&str], filenames: &[&str]) -> Value {
+        json!({
+            "reason": "compiler-artifact",
+            "manifest_path": "/addon/Cargo.toml",
+            "target": { "crate_types": crate_types },
+            "filenames": filenames,
+        })
+    }
+
+    #[test]
+    fn finds_cdylib_artifacts() {
+        let artifact = cdylib_artifact(&artifact_message(
+            &["cdylib", "rlib"],
+            &["/target/libaddon.rlib", "/target/libaddon.so"],
+        ))
+        .unwrap();
+        assert_eq!(artifact.path, PathBuf::from("/target/libaddon.so"));
+        assert_eq!(artifact.manifest_path, PathBuf::from("/addon/Cargo.toml"));
+
+        let artifact = cdylib_artifact(&artifact_message(
+            &["cdylib"],
+            &["/target/addon.dll", "/target/addon.dll.lib"],
+        ))
+        .unwrap();
+        assert_eq!(artifact.path, PathBuf::from("/target/addon.dll"));
+    }
+
+    #[test]
+    fn skips_other_messages() {
+        assert!(cdylib_artifact(&artifact_message(
+            &["rlib"],
+            &["/target/libaddon.rlib"],
+        ))
+        .is_none());
+        assert!(cdylib_artifact(&artifact_message(&["cdylib"], &[])).is_none());
+        assert!(cdylib_artifact(&json!({
+            "reason": "build-script-executed",
+            "target": { "crate_types": ["cdylib"] },
+            "filenames": ["/target/libaddon.so"],
+        }))
+        .is_none());
+    }
+
+    #[test]
+    fn writes_package_json() {
+        let package = json!({
+            "name": "napi-addon",
+            "version": "1.2.3",
+            "description": "An \"addon\"",
+            "license": null,
+        });
+
+        assert_eq!(
+            package_json(&package, "addon", true),
+            r#"{
+  "name": "addon",
+  "version": "1.2.3",
+  "description": "An \"addon\"",
+  "main": "addon.node",
+  "types": "index.d.ts"
+}
+"#,
+        );
+        assert_eq!(
+            package_json(&package, "addon", false),
+            r#"{
+  "name": "addon",
+  "version": "1.2.3",
+  "description": "An \"addon\"",
+  "main": "addon.node"
+}
+"#,
+        );
+    }
+}
@@ -1,6 +1,7 @@
 'use strict';
 
-const addon = require('./build/Release/example.node');
+const addon = require('./example.node');
 
 addon.hello();
 console.log(addon.add(1, 2));
+console.log(addon.sum(1, 2, 3, 4));
@@ -0,0 +1,216 @@
+// Configures the linking of N-API addons built as a `cdylib`, and is meant
+// to be called from their `build.rs`:
+//
+//     extern crate napi_build;
+//
+//     fn main() {
+//         napi_build::setup();
+//     }
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// The highest N-API version that a `napiN` feature may request.
+pub const MAX_NAPI_VERSION: u32 = 9;
+
+pub fn setup() {
+    let version = requested_napi_version();
+
+    if let Some(version) = version {
+        check_headers(version);
+        check_node(version);
+    }
+
+    match env::var("CARGO_CFG_TARGET_OS").as_ref().map(String::as_str) {
+        // The N-API functions are looked up in the process that loads the
+        // addon, which the linker has to be told about.
+        Ok("macos") | Ok("ios") => {
+            println!(
+                "cargo:rustc-cdylib-link-arg=-Wl,-undefined,dynamic_lookup"
+            );
+        }
+        // The N-API functions are imported from `node.exe`.
+        Ok("windows") => link_node_lib(),
+        // Shared objects may have undefined symbols by default.
+        _ => {}
+    }
+}
+
+// The N-API version requested with the `napiN` features of the `napi`
+// dependency of the addon, which its build script passes on to the addon's
+// as `DEP_NAPI_NAPI_VERSION`.  There is none when the addon does not depend
+// on `napi` directly, or with none of the features enabled.
+pub fn requested_napi_version() -> Option<u32> {
+    let version = env::var("DEP_NAPI_NAPI_VERSION").ok()?;
+
+    match parse_napi_version(&version) {
+        Ok(version) => Some(version),
+        Err(message) => panic!("{}", message),
+    }
+}
+
+fn parse_napi_version(version: &str) -> Result<u32, String> {
+    match version.parse::<u32>() {
+        Ok(version) if version > 0 && version <= MAX_NAPI_VERSION => {
+            Ok(version)
+        }
+        _ => Err(format!(
+            "`napi` requests N-API version `{}`, but the supported ones are \
+             1 to {}",
+            version, MAX_NAPI_VERSION,
+        )),
+    }
+}
+
+// Fails when the Node.js headers in `NAPI_NODE_INCLUDE_DIR`, if set, are
+// missing or do not support the requested N-API version, which is how to make
+// sure that an addon is built for the Node.js it is going to run in.
+fn check_headers(version: u32) {
+    println!("cargo:rerun-if-env-changed=NAPI_NODE_INCLUDE_DIR");
+
+    let dir = match env::var_os("NAPI_NODE_INCLUDE_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => return,
+    };
+    let path = dir.join("node_version.h");
+    println!("cargo:rerun-if-changed={}", path.display());
+
+    let header = match fs::read_to_string(&path) {
+        Ok(header) => header,
+        Err(error) => panic!(
+            "NAPI_NODE_INCLUDE_DIR must be set to the `include/node` \
+             directory of Node.js, but {} could not be read: {}",
+            path.display(),
+            error,
+        ),
+    };
+
+    if let Err(message) = check_header(&header, version) {
+        panic!("{}: {}", path.display(), message);
+    }
+}
+
+fn check_header(header: &str, version: u32) -> Result<(), String> {
+    match header_napi_version(header) {
+        Some(supported) if supported >= version => Ok(()),
+        Some(supported) => Err(format!(
+            "the `napi{}` feature requires N-API {}, but these headers only \
+             support N-API {}",
+            version, version, supported,
+        )),
+        None => Err("NAPI_VERSION is not defined".to_owned()),
+    }
+}
+
+// The N-API version that `node_version.h` declares Node.js to support.
+fn header_napi_version(header: &str) -> Option<u32> {
+    header.lines().find_map(|line| {
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+            (Some("#define"), Some("NAPI_VERSION"), Some(version)) => {
+                version.parse().ok()
+            }
+            _ => None,
+        }
+    })
+}
+
+// Warns when the Node.js installed, if any, does not support the requested
+// N-API version.  It may well be a different one that runs the addon, so
+// this is not an error.
+fn check_node(version: u32) {
+    println!("cargo:rerun-if-env-changed=NODE");
+
+    let node = env::var_os("NODE").unwrap_or_else(|| "node".into());
+    let output = match Command::new(node)
+        .args(["-p", "process.versions.napi + ' ' + process.version"])
+        .output()
+    {
+        Ok(ref output) if output.status.success() => {
+            String::from_utf8_lossy(&output.stdout).into_owned()
+        }
+        _ => return,
+    };
+
+    let mut parts = output.split_whitespace();
+    let supported = parts.next().and_then(|napi| napi.parse::<u32>().ok());
+    let node_version = parts.next().unwrap_or("");
+
+    if let Some(supported) = supported {
+        if supported < version {
+            println!(
+                "cargo:warning=the `napi{}` feature requires N-API {}, but \
+                 Node.js {} only supports N-API {}",
+                version, version, node_version, supported,
+            );
+        }
+    }
+}
+
+fn link_node_lib() {
+    println!("cargo:rerun-if-env-changed=NAPI_NODE_LIB_DIR");
+
+    match env::var_os("NAPI_NODE_LIB_DIR") {
+        Some(dir) => {
+            println!(
+                "cargo:rustc-link-search=native={}",
+                Path::new(&dir).display(),
+            );
+            println!("cargo:rustc-link-lib=dylib=node");
+        }
+        None => panic!(
+            "NAPI_NODE_LIB_DIR must be set to the directory containing \
+             node.lib, which is published for every release of Node.js at \
+             https://nodejs.org/dist/<version>/win-x64/node.lib"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_header, header_napi_version, parse_napi_version};
+
+    const NODE_20_HEADER: &str = "\
+#define NODE_MODULE_VERSION 115
+#endif
+
+// The NAPI_VERSION provided by this version of the runtime.
+#define NAPI_VERSION 9
+
+#define NODE_API_DEFAULT_MODULE_API_VERSION 8
+";
+
+    #[test]
+    fn parses_requested_versions() {
+        assert_eq!(parse_napi_version("1"), Ok(1));
+        assert_eq!(parse_napi_version("9"), Ok(9));
+        assert!(parse_napi_version("0").is_err());
+        assert!(parse_napi_version("10").is_err());
+        assert!(parse_napi_version("napi9").is_err());
+    }
+
+    #[test]
+    fn reads_header_versions() {
+        assert_eq!(header_napi_version(NODE_20_HEADER), Some(9));
+        assert_eq!(header_napi_version("#define NAPI_VERSION_X 3"), None);
+        assert_eq!(header_napi_version(""), None);
+    }
+
+    #[test]
+    fn checks_header_versions() {
+        assert_eq!(check_header(NODE_20_HEADER, 8), Ok(()));
+        assert_eq!(check_header(NODE_20_HEADER, 9), Ok(()));
+        assert_eq!(
+            check_header("#define NAPI_VERSION 8", 9),
+            Err("the `napi9` feature requires N-API 9, but these headers \
+                 only support N-API 8"
+                .to_owned())
+        );
+        assert_eq!(
+            check_header("", 1),
+            Err("NAPI_VERSION is not defined".to_owned())
+        );
+    }
+}
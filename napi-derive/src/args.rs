@@ -0,0 +1,569 @@
+use proc_macro2::TokenStream as Tokens;
+use proc_macro2::Span;
+use syn::{Data, DataEnum, DeriveInput, Error, Expr, Field, Fields,
+          GenericParam, Generics, Ident, Lifetime, LifetimeParam, LitStr,
+          Path, Result, Type};
+
+use case::to_camel_case;
+use diagnostics::{check_field_type, unknown_attribute};
+use typedef::ts_arg_type;
+
+enum ArgKind {
+    Required,
+    Optional,
+    Default(Option<Expr>),
+    Rest,
+    This,
+}
+
+const ARG_ATTRIBUTES: &[&str] = &[
+    "default",
+    "rest",
+    "this",
+    "range",
+    "non_empty",
+    "max_len",
+    "validate",
+];
+
+enum Validation {
+    Range(Expr),
+    NonEmpty,
+    MaxLen(Expr),
+    Custom(Path),
+}
+
+struct Arg<'a> {
+    field: &'a Field,
+    ident: Option<&'a Ident>,
+    ty: &'a Type,
+    kind: ArgKind,
+    validations: Vec<Validation>,
+}
+
+pub fn impl_napi_args(ast: &DeriveInput) -> Result<Tokens> {
+    let name = &ast.ident;
+
+    let body = match ast.data {
+        Data::Struct(ref data) => {
+            let (parser, _) = gen_parser(&data.fields, quote! { Self })?;
+            parser
+        }
+        Data::Enum(ref data) => gen_overloads(data)?,
+        Data::Union(ref data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "NapiArgs can only be derived for structs and enums",
+            ));
+        }
+    };
+
+    let (generics, env) = gen_generics(&ast.generics);
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::napi::NapiArgs<#env> for #name #ty_generics
+            #where_clause
+        {
+            #[allow(clippy::redundant_closure_call)]
+            fn from_context(
+                context: &::napi::CallContext<#env>,
+            ) -> ::napi::NapiResult<Self> {
+                #[allow(unused_imports)]
+                use ::napi::{FromNapi, NapiError, NapiString, NapiValue,
+                             NapiValueType};
+
+                #[allow(unused_variables)]
+                let env = context.env();
+                #[allow(unused_variables)]
+                let argc = context.argc();
+
+                #body
+            }
+        }
+    })
+}
+
+// Renders the arguments as the parameter list of a TypeScript function,
+// with the `this` argument first.  `Option` arguments are declared as
+// `x?: T | null`, since both a missing argument and `null` are `None`, while
+// `None` is only ever returned as `null`, hence `T | null` for results.
+pub fn ts_params(fields: &Fields) -> Result<String> {
+    let args = fields
+        .iter()
+        .map(parse_arg)
+        .collect::<Result<Vec<_>>>()?;
+
+    let positional = args
+        .iter()
+        .filter(|arg| !arg.is_this())
+        .collect::<Vec<_>>();
+
+    // Optional arguments that precede a required one may only be omitted by
+    // passing `undefined`.
+    let required = positional
+        .iter()
+        .rposition(|arg| arg.is_required())
+        .map_or(0, |idx| idx + 1);
+
+    let this = args
+        .iter()
+        .filter(|arg| arg.is_this())
+        .map(|arg| format!("this: {}", ts_arg_type(arg.ty)));
+    let positional = positional
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| {
+            let name = match arg.ident {
+                Some(ident) => to_camel_case(&ident.to_string()),
+                None => format!("arg{}", idx + 1),
+            };
+            let ty = ts_arg_type(arg.ty);
+
+            match arg.kind {
+                ArgKind::Optional | ArgKind::Default(_) if idx < required => {
+                    format!("{}: {} | undefined", name, ty)
+                }
+                ArgKind::Optional | ArgKind::Default(_) => {
+                    format!("{}?: {}", name, ty)
+                }
+                ArgKind::Rest => format!("...{}: {}", name, ty),
+                _ => format!("{}: {}", name, ty),
+            }
+        });
+
+    Ok(this.chain(positional).collect::<Vec<_>>().join(", "))
+}
+
+// Extends the generics of the input with the lifetime of the environment,
+// which outlives the other lifetimes, and a `FromNapi` bound on every type
+// parameter.  The environment lifetime is the one named `'env`, or else the
+// first one, or else a new `'env`.
+fn gen_generics(input: &Generics) -> (Generics, Lifetime) {
+    let mut generics = input.clone();
+
+    let env = match input
+        .lifetimes()
+        .find(|param| param.lifetime.ident == "env")
+        .or_else(|| input.lifetimes().next())
+    {
+        Some(param) => param.lifetime.clone(),
+        None => {
+            let env = Lifetime::new("'env", Span::call_site());
+            generics.params.insert(
+                0,
+                GenericParam::Lifetime(LifetimeParam::new(env.clone())),
+            );
+            env
+        }
+    };
+
+    // Every value comes from the same environment, so the other lifetimes
+    // may not outlive its.
+    for param in input.lifetimes() {
+        let lifetime = &param.lifetime;
+        if *lifetime != env {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #env: #lifetime });
+        }
+    }
+
+    for param in input.type_params() {
+        let ident = &param.ident;
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { #ident: ::napi::FromNapi<#env> });
+    }
+
+    (generics, env)
+}
+
+// Generates the statements that check and convert the arguments into
+// `constructor`, along with the signature they describe.
+fn gen_parser(
+    fields: &Fields,
+    constructor: Tokens,
+) -> Result<(Tokens, String)> {
+    let args = fields
+        .iter()
+        .map(parse_arg)
+        .collect::<Result<Vec<_>>>()?;
+
+    let rest = match args.iter().position(|arg| arg.is_rest()) {
+        Some(idx) if idx + 1 != args.len() => {
+            return Err(Error::new_spanned(
+                args[idx].field,
+                "#[napi(rest)] must be the last field",
+            ));
+        }
+        rest => rest,
+    };
+
+    let positional = args
+        .iter()
+        .filter(|arg| !arg.is_this())
+        .collect::<Vec<_>>();
+    let count = positional.len() - rest.map_or(0, |_| 1);
+    let required = positional
+        .iter()
+        .take(count)
+        .rposition(|arg| arg.is_required())
+        .map_or(0, |idx| idx + 1);
+
+    let initializer = gen_initializer(fields, &args, constructor);
+    let check_count = gen_check_count(required, count, rest.is_some());
+
+    let signature = positional
+        .iter()
+        .enumerate()
+        .map(|(idx, arg)| arg.signature(idx))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    Ok((
+        quote! {
+            #check_count
+
+            Ok(#initializer)
+        },
+        format!("({})", signature),
+    ))
+}
+
+// Each variant of an enum is an alternative argument list, and the first one
+// that matches is picked.  An exception thrown by JavaScript while trying one
+// is rethrown rather than taken for a mismatch.
+fn gen_overloads(data: &DataEnum) -> Result<Tokens> {
+    let mut parsers = Vec::new();
+    let mut signatures = Vec::new();
+
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let (parser, signature) =
+            gen_parser(&variant.fields, quote! { Self::#ident })?;
+
+        parsers.push(parser);
+        signatures.push(signature);
+    }
+
+    Ok(quote! {
+        let mut reasons = Vec::new();
+
+        #(
+            match (|| -> ::napi::NapiResult<Self> { #parsers })() {
+                Ok(args) => return Ok(args),
+                Err(error) => {
+                    if error.is_thrown() {
+                        return Err(error);
+                    }
+                    reasons.push(error.thrown_message(env));
+                }
+            }
+        )*
+
+        let mut message = String::from(
+            "No overload matches the arguments, expected one of:",
+        );
+        for (signature, reason) in [#(#signatures),*].iter().zip(reasons) {
+            message.push_str(&format!("\n  {}\n    {}", signature, reason));
+        }
+
+        let message = NapiString::from_str(env, &message)?;
+        Err(NapiError::type_error(env, &message))
+    })
+}
+
+impl<'a> Arg<'a> {
+    fn is_required(&self) -> bool {
+        matches!(self.kind, ArgKind::Required)
+    }
+
+    fn is_rest(&self) -> bool {
+        matches!(self.kind, ArgKind::Rest)
+    }
+
+    fn is_this(&self) -> bool {
+        matches!(self.kind, ArgKind::This)
+    }
+
+    // The argument as a parameter of a TypeScript signature.
+    fn signature(&self, idx: usize) -> String {
+        let name = match self.ident {
+            Some(ident) => to_camel_case(&ident.to_string()),
+            None => format!("arg{}", idx + 1),
+        };
+        let ty = ts_arg_type(self.ty);
+
+        match self.kind {
+            ArgKind::Required | ArgKind::This => format!("{}: {}", name, ty),
+            ArgKind::Optional | ArgKind::Default(_) => {
+                format!("{}?: {}", name, ty)
+            }
+            ArgKind::Rest => format!("...{}: {}", name, ty),
+        }
+    }
+
+    fn context(&self, idx: usize) -> String {
+        match self.ident {
+            Some(ident) => format!("argument {} `{}`", idx + 1, ident),
+            None => format!("argument {}", idx + 1),
+        }
+    }
+}
+
+fn parse_arg(field: &Field) -> Result<Arg<'_>> {
+    check_field_type(&field.ty)?;
+
+    let mut kind = if is_option(&field.ty) {
+        ArgKind::Optional
+    } else {
+        ArgKind::Required
+    };
+
+    let mut validations = Vec::new();
+
+    let attrs = field.attrs.iter().filter(|attr| attr.path().is_ident("napi"));
+
+    for attr in attrs {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rest") {
+                kind = ArgKind::Rest;
+            } else if meta.path.is_ident("this") {
+                kind = ArgKind::This;
+            } else if meta.path.is_ident("default") {
+                kind = if meta.input.peek(syn::Token![=]) {
+                    ArgKind::Default(Some(meta.value()?.parse()?))
+                } else {
+                    ArgKind::Default(None)
+                };
+            } else if meta.path.is_ident("range") {
+                validations.push(Validation::Range(meta.value()?.parse()?));
+            } else if meta.path.is_ident("non_empty") {
+                validations.push(Validation::NonEmpty);
+            } else if meta.path.is_ident("max_len") {
+                validations.push(Validation::MaxLen(meta.value()?.parse()?));
+            } else if meta.path.is_ident("validate") {
+                let path = meta.value()?.parse::<LitStr>()?.parse()?;
+                validations.push(Validation::Custom(path));
+            } else {
+                return Err(unknown_attribute(&meta, ARG_ATTRIBUTES));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(Arg {
+        field,
+        ident: field.ident.as_ref(),
+        ty: &field.ty,
+        kind,
+        validations,
+    })
+}
+
+pub fn is_option(ty: &Type) -> bool {
+    match *ty {
+        Type::Path(ref path) => path.qself.is_none()
+            && path.path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+fn gen_check_count(required: usize, count: usize, rest: bool) -> Tokens {
+    let message = if required == count && !rest {
+        quote! { format!("Expected {} arguments, but got {}", #count, argc) }
+    } else if rest {
+        quote! {
+            format!(
+                "Expected at least {} arguments, but got {}",
+                #required,
+                argc,
+            )
+        }
+    } else {
+        quote! {
+            format!(
+                "Expected from {} to {} arguments, but got {}",
+                #required,
+                #count,
+                argc,
+            )
+        }
+    };
+
+    let condition = if rest {
+        if required == 0 {
+            return quote! {};
+        }
+        quote! { argc < #required }
+    } else if required == count {
+        quote! { argc != #count }
+    } else if required == 0 {
+        quote! { argc > #count }
+    } else {
+        quote! { !(#required..=#count).contains(&argc) }
+    };
+
+    quote! {
+        if #condition {
+            let message = NapiString::from_str(env, &#message)?;
+            return Err(NapiError::type_error(env, &message));
+        }
+    }
+}
+
+fn gen_initializer(
+    fields: &Fields,
+    args: &[Arg<'_>],
+    constructor: Tokens,
+) -> Tokens {
+    let mut idx = 0;
+    let values = args
+        .iter()
+        .map(|arg| {
+            let value = gen_value(idx, arg);
+            if !arg.is_this() {
+                idx += 1;
+            }
+            value
+        })
+        .collect::<Vec<_>>();
+
+    match *fields {
+        Fields::Named(_) => {
+            let idents = args.iter().map(|arg| arg.ident);
+            quote! { #constructor { #(#idents: #values),* } }
+        }
+        Fields::Unnamed(_) => quote! { #constructor ( #(#values),* ) },
+        Fields::Unit => constructor,
+    }
+}
+
+// Generates the conversion of an argument followed by its validation.
+fn gen_value(idx: usize, arg: &Arg<'_>) -> Tokens {
+    let value = gen_converted_value(idx, arg);
+
+    if arg.validations.is_empty() {
+        return value;
+    }
+
+    let context = match arg.kind {
+        ArgKind::Rest => match arg.ident {
+            Some(ident) => format!("arguments `{}`", ident),
+            None => "rest arguments".to_owned(),
+        },
+        _ => arg.context(idx),
+    };
+
+    let checks = arg.validations.iter().map(|validation| {
+        let check = match *validation {
+            Validation::Range(ref range) => {
+                let range_str = quote!(#range).to_string().replace(' ', "");
+                quote! {
+                    ::napi::validate::check_range(
+                        env,
+                        value,
+                        &(#range),
+                        #range_str,
+                    )
+                }
+            }
+            Validation::NonEmpty => quote! {
+                ::napi::validate::check_non_empty(env, value)
+            },
+            Validation::MaxLen(ref max) => quote! {
+                ::napi::validate::check_max_len(env, value, #max)
+            },
+            Validation::Custom(ref path) => quote! {
+                ::napi::validate::check_custom(env, #path(value))
+            },
+        };
+
+        quote! {
+            #check.map_err(|error| error.context(env, #context))?;
+        }
+    });
+
+    // Optional arguments are only validated when they are present.
+    let checks = if is_option(arg.ty) {
+        quote! {
+            if let Some(ref value) = value {
+                #(#checks)*
+            }
+        }
+    } else {
+        quote! {
+            {
+                let value = &value;
+                #(#checks)*
+            }
+        }
+    };
+
+    quote! {
+        {
+            let value = #value;
+            #checks
+            value
+        }
+    }
+}
+
+fn gen_converted_value(idx: usize, arg: &Arg<'_>) -> Tokens {
+    match arg.kind {
+        ArgKind::Required | ArgKind::Optional => {
+            gen_conversion(quote! { context.arg(#idx)? }, &arg.context(idx))
+        }
+        ArgKind::This => quote! { context.this()? },
+        ArgKind::Default(ref default) => {
+            let default = match *default {
+                Some(ref expr) => quote! { #expr },
+                None => quote! { ::std::default::Default::default() },
+            };
+            let conversion =
+                gen_conversion(quote! { value }, &arg.context(idx));
+
+            quote! {
+                {
+                    let value = context.arg(#idx)?;
+                    if value.value_type()? == NapiValueType::Undefined {
+                        #default
+                    } else {
+                        #conversion
+                    }
+                }
+            }
+        }
+        ArgKind::Rest => {
+            let context = match arg.ident {
+                Some(ident) => format!("argument {{}} `{}`", ident),
+                None => "argument {}".to_owned(),
+            };
+
+            quote! {
+                (#idx..argc)
+                    .map(|idx| {
+                        <_ as FromNapi>::from_napi(context.arg(idx)?)
+                            .map_err(|error| {
+                                error.context(env, &format!(#context, idx + 1))
+                            })
+                    })
+                    .collect::<::napi::NapiResult<_>>()?
+            }
+        }
+    }
+}
+
+fn gen_conversion(value: Tokens, context: &str) -> Tokens {
+    quote! {
+        <_ as FromNapi>::from_napi(#value)
+            .map_err(|error| error.context(env, #context))?
+    }
+}
@@ -0,0 +1,168 @@
+// Converts a snake_case identifier to camelCase, keeping leading
+// underscores intact.
+pub fn to_camel_case(name: &str) -> String {
+    let trimmed = name.trim_start_matches('_');
+    let mut result = name[..name.len() - trimmed.len()].to_owned();
+    let mut upper = false;
+
+    for ch in trimmed.chars() {
+        if ch == '_' {
+            upper = true;
+        } else if upper {
+            result.extend(ch.to_uppercase());
+            upper = false;
+        } else {
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
+// The values accepted by `#[napi(rename_all = "...")]`.
+#[derive(Clone, Copy, Debug)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+impl RenameRule {
+    pub fn parse(rule: &str) -> Option<RenameRule> {
+        match rule {
+            "lowercase" => Some(RenameRule::Lower),
+            "UPPERCASE" => Some(RenameRule::Upper),
+            "camelCase" => Some(RenameRule::Camel),
+            "PascalCase" => Some(RenameRule::Pascal),
+            "snake_case" => Some(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(RenameRule::ScreamingSnake),
+            "kebab-case" => Some(RenameRule::Kebab),
+            _ => None,
+        }
+    }
+
+    // Renames a Rust identifier, which may be either in snake_case (fields)
+    // or in PascalCase (variants).
+    pub fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+
+        match self {
+            RenameRule::Lower => words.concat(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(idx, word)| {
+                    if idx == 0 {
+                        word.clone()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            RenameRule::Pascal => {
+                words.iter().map(|word| capitalize(word)).collect()
+            }
+            RenameRule::Snake => words.join("_"),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-"),
+        }
+    }
+}
+
+// Splits an identifier into lowercase words at underscores and at the
+// lowercase-to-uppercase transitions.
+fn split_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut prev_lower = false;
+
+    for ch in name.chars() {
+        let boundary = ch == '_' || (ch.is_uppercase() && prev_lower);
+        if boundary && !word.is_empty() {
+            words.push(word);
+            word = String::new();
+        }
+
+        if ch != '_' {
+            word.extend(ch.to_lowercase());
+        }
+
+        prev_lower = ch.is_lowercase() || ch.is_ascii_digit();
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_snake_case_to_camel_case() {
+        assert_eq!(to_camel_case("add"), "add");
+        assert_eq!(to_camel_case("read_file_sync"), "readFileSync");
+        assert_eq!(to_camel_case("_private_name"), "_privateName");
+        assert_eq!(to_camel_case("__proto"), "__proto");
+        assert_eq!(to_camel_case("to_u8"), "toU8");
+    }
+
+    #[test]
+    fn parses_rename_rules() {
+        assert!(RenameRule::parse("camelCase").is_some());
+        assert!(RenameRule::parse("kebab-case").is_some());
+        assert!(RenameRule::parse("camel").is_none());
+        assert!(RenameRule::parse("CamelCase").is_none());
+    }
+
+    #[test]
+    fn renames_fields() {
+        let cases = [
+            ("lowercase", "maxretries"),
+            ("UPPERCASE", "MAXRETRIES"),
+            ("camelCase", "maxRetries"),
+            ("PascalCase", "MaxRetries"),
+            ("snake_case", "max_retries"),
+            ("SCREAMING_SNAKE_CASE", "MAX_RETRIES"),
+            ("kebab-case", "max-retries"),
+        ];
+
+        for &(rule, expected) in &cases {
+            let rule = RenameRule::parse(rule).unwrap();
+            assert_eq!(rule.apply("max_retries"), expected);
+        }
+    }
+
+    #[test]
+    fn renames_variants() {
+        let cases = [
+            ("camelCase", "darkGreen"),
+            ("snake_case", "dark_green"),
+            ("kebab-case", "dark-green"),
+            ("SCREAMING_SNAKE_CASE", "DARK_GREEN"),
+        ];
+
+        for &(rule, expected) in &cases {
+            let rule = RenameRule::parse(rule).unwrap();
+            assert_eq!(rule.apply("DarkGreen"), expected);
+        }
+
+        assert_eq!(RenameRule::Camel.apply("Utf8Text"), "utf8Text");
+    }
+}
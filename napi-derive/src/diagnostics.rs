@@ -0,0 +1,161 @@
+use proc_macro2::TokenStream as Tokens;
+use syn::meta::ParseNestedMeta;
+use syn::{Error, GenericParam, Generics, LitStr, Result, Type};
+
+use case::RenameRule;
+
+// Like `Error::into_compile_error`, but without the `::core` path, which is
+// not available to crates in the 2015 edition.
+pub fn into_compile_error(error: Error) -> Tokens {
+    let errors = error.into_iter().map(|error| {
+        let message = error.to_string();
+        quote_spanned! { error.span() => compile_error!(#message); }
+    });
+
+    quote! { #(#errors)* }
+}
+
+// The error for a `#[napi(...)]` key that is not one of `expected`,
+// suggesting the closest one in case of a typo.
+pub fn unknown_attribute(
+    meta: &ParseNestedMeta<'_>,
+    expected: &[&str],
+) -> Error {
+    let name = meta
+        .path
+        .get_ident()
+        .map_or_else(String::new, |ident| ident.to_string());
+
+    let suggestion = expected
+        .iter()
+        .map(|candidate| (distance(&name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= 2)
+        .min();
+
+    match suggestion {
+        Some((_, candidate)) => meta.error(format!(
+            "unknown napi attribute `{}`, did you mean `{}`?",
+            name, candidate,
+        )),
+        None => meta.error(format!(
+            "unknown napi attribute `{}`, expected one of: {}",
+            name,
+            expected.join(", "),
+        )),
+    }
+}
+
+// Parses the value of a `rename_all` attribute.
+pub fn rename_rule(meta: &ParseNestedMeta<'_>) -> Result<RenameRule> {
+    let rule = meta.value()?.parse::<LitStr>()?;
+
+    RenameRule::parse(&rule.value()).ok_or_else(|| {
+        Error::new_spanned(
+            &rule,
+            "unknown rename rule, expected one of: \"lowercase\", \
+             \"UPPERCASE\", \"camelCase\", \"PascalCase\", \"snake_case\", \
+             \"SCREAMING_SNAKE_CASE\", \"kebab-case\"",
+        )
+    })
+}
+
+// The generated impls only support a single lifetime, which is the one of
+// the environment.
+pub fn check_generics(generics: &Generics, derive: &str) -> Result<()> {
+    let mut lifetimes = 0;
+
+    for param in &generics.params {
+        match *param {
+            GenericParam::Lifetime(_) => {
+                lifetimes += 1;
+                if lifetimes > 1 {
+                    return Err(Error::new_spanned(
+                        param,
+                        format!(
+                            "{} supports at most one lifetime parameter",
+                            derive,
+                        ),
+                    ));
+                }
+            }
+            GenericParam::Type(_) | GenericParam::Const(_) => {
+                return Err(Error::new_spanned(
+                    param,
+                    format!("{} does not support generic parameters", derive),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Rejects the types that can never be converted from JavaScript values.
+pub fn check_field_type(ty: &Type) -> Result<()> {
+    let message = match *ty {
+        Type::Reference(_) => {
+            "references cannot be converted from JavaScript values, use an \
+             owned type such as `String` or `Vec<T>` instead"
+        }
+        Type::Ptr(_) => {
+            "raw pointers cannot be converted from JavaScript values"
+        }
+        Type::ImplTrait(_) | Type::TraitObject(_) | Type::Infer(_) => {
+            "the type of the field must be known"
+        }
+        Type::Never(_) | Type::BareFn(_) => {
+            "the type cannot be converted from JavaScript values"
+        }
+        Type::Group(ref group) => return check_field_type(&group.elem),
+        Type::Paren(ref paren) => return check_field_type(&paren.elem),
+        _ => return Ok(()),
+    };
+
+    Err(Error::new_spanned(ty, message))
+}
+
+// The Levenshtein distance between two strings.
+fn distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+
+        for (j, &cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == cb {
+                prev
+            } else {
+                prev.min(row[j]).min(current) + 1
+            };
+            prev = current;
+        }
+    }
+
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::distance;
+
+    #[test]
+    fn counts_edits() {
+        assert_eq!(distance("rename", "rename"), 0);
+        assert_eq!(distance("renam", "rename"), 1);
+        assert_eq!(distance("renamee", "rename"), 1);
+        assert_eq!(distance("renane", "rename"), 1);
+        assert_eq!(distance("renmae", "rename"), 2);
+        assert_eq!(distance("", "skip"), 4);
+        assert_eq!(distance("skip", ""), 4);
+        assert_eq!(distance("default", "rest"), 5);
+    }
+
+    #[test]
+    fn counts_characters_rather_than_bytes() {
+        assert_eq!(distance("défaut", "default"), 2);
+        assert_eq!(distance("ñ", "n"), 1);
+    }
+}
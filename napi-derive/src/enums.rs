@@ -0,0 +1,606 @@
+use proc_macro2::{Span, TokenStream as Tokens};
+use syn::spanned::Spanned;
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr, Result,
+          Variant};
+
+use case::RenameRule;
+use diagnostics::{check_field_type, check_generics, rename_rule,
+                  unknown_attribute};
+use object::{gen_bindings, gen_from_object, gen_to_object,
+             parse_object_fields, ts_object_type};
+use typedef::{gen_tracking, ts_docs, ts_property, ts_type, write};
+
+// How the variants are represented in JavaScript.
+enum Repr {
+    // Unit variants as strings.
+    String,
+    // Unit variants as their discriminants.
+    Numeric,
+    // Objects with a property holding the name of the variant.
+    Tagged(String),
+    // Whatever the fields convert to, trying the variants in order.
+    Untagged,
+}
+
+struct EnumVariant<'a> {
+    variant: &'a Variant,
+    js_name: String,
+    // The rule for renaming the fields of a struct variant.
+    rename_all: Option<RenameRule>,
+}
+
+pub fn impl_napi_enum(ast: &DeriveInput) -> Result<Tokens> {
+    let name = &ast.ident;
+
+    check_generics(&ast.generics, "NapiEnum")?;
+
+    let data = match ast.data {
+        Data::Enum(ref data) => data,
+        Data::Struct(ref data) => {
+            return Err(Error::new_spanned(
+                data.struct_token,
+                "NapiEnum can only be derived for enums, use NapiObject for \
+                 structs",
+            ));
+        }
+        Data::Union(ref data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "NapiEnum can only be derived for enums",
+            ));
+        }
+    };
+
+    for field in data.variants.iter().flat_map(|variant| &variant.fields) {
+        check_field_type(&field.ty)?;
+    }
+
+    let (repr, rename_all) = parse_enum_attrs(&ast.attrs)?;
+    let is_unit_only = data
+        .variants
+        .iter()
+        .all(|variant| matches!(variant.fields, Fields::Unit));
+
+    let repr = match repr {
+        Some(Repr::Numeric) if !is_unit_only => {
+            let variant = data
+                .variants
+                .iter()
+                .find(|variant| !matches!(variant.fields, Fields::Unit));
+            return Err(Error::new_spanned(
+                variant,
+                "#[napi(numeric)] enums can only have unit variants",
+            ));
+        }
+        Some(repr) => repr,
+        None if is_unit_only => Repr::String,
+        None => Repr::Tagged("type".to_owned()),
+    };
+
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| {
+            let (js_name, fields_rename_all) = parse_variant_attrs(variant)?;
+            let js_name = js_name.unwrap_or_else(|| {
+                rename_all
+                    .unwrap_or(RenameRule::Camel)
+                    .apply(&variant.ident.to_string())
+            });
+
+            Ok(EnumVariant {
+                variant,
+                js_name,
+                rename_all: fields_rename_all,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (from_body, to_body) = match repr {
+        Repr::String => gen_string(&variants),
+        Repr::Numeric => gen_numeric(&variants),
+        Repr::Tagged(ref tag) => gen_tagged(&variants, tag)?,
+        Repr::Untagged => gen_untagged(name, &variants)?,
+    };
+
+    let gen_lifetime = if ast.generics.lifetimes().count() > 0 {
+        quote! { <'env> }
+    } else {
+        quote! {}
+    };
+
+    // Tagged enums can themselves be the fields of newtype variants.
+    let object_value = match repr {
+        Repr::Tagged(_) => quote! {
+            impl<'env> ::napi::NapiObjectValue for #name #gen_lifetime {}
+        },
+        _ => quote! {},
+    };
+
+    write(
+        "type",
+        &name.to_string(),
+        &format!(
+            "{}{}",
+            ts_docs(&ast.attrs, ""),
+            ts_declaration(name, &repr, &variants)?,
+        ),
+    )?;
+
+    let tracking = gen_tracking();
+
+    Ok(quote! {
+        #tracking
+
+        impl<'env> ::napi::FromNapi<'env> for #name #gen_lifetime {
+            fn from_napi(
+                value: ::napi::NapiAny<'env>,
+            ) -> ::napi::NapiResult<Self> {
+                #[allow(unused_imports)]
+                use ::napi::{FromNapi, NapiError, NapiString, NapiValue,
+                             NapiValueType};
+
+                #[allow(unused_variables)]
+                let env = value.env();
+
+                #from_body
+            }
+        }
+
+        impl<'env> ::napi::ToNapi<'env> for #name #gen_lifetime {
+            fn to_napi(
+                &self,
+                env: &'env ::napi::NapiEnv,
+            ) -> ::napi::NapiResult<::napi::NapiAny<'env>> {
+                #[allow(unused_imports)]
+                use ::napi::{NapiArray, NapiObject, NapiValue, ToNapi};
+
+                #to_body
+            }
+        }
+
+        #object_value
+    })
+}
+
+fn parse_enum_attrs(
+    attrs: &[Attribute],
+) -> Result<(Option<Repr>, Option<RenameRule>)> {
+    let mut repr = None;
+    let mut rename_all = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("napi")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("numeric") {
+                repr = Some(Repr::Numeric);
+            } else if meta.path.is_ident("untagged") {
+                repr = Some(Repr::Untagged);
+            } else if meta.path.is_ident("tag") {
+                let tag = meta.value()?.parse::<LitStr>()?;
+                repr = Some(Repr::Tagged(tag.value()));
+            } else if meta.path.is_ident("rename_all") {
+                rename_all = Some(rename_rule(&meta)?);
+            } else {
+                return Err(unknown_attribute(
+                    &meta,
+                    &["numeric", "untagged", "tag", "rename_all"],
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok((repr, rename_all))
+}
+
+fn parse_variant_attrs(
+    variant: &Variant,
+) -> Result<(Option<String>, Option<RenameRule>)> {
+    let mut js_name = None;
+    let mut rename_all = None;
+
+    for attr in variant.attrs.iter().filter(|a| a.path().is_ident("napi")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                js_name = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("rename_all") {
+                rename_all = Some(rename_rule(&meta)?);
+            } else {
+                return Err(unknown_attribute(&meta, &["rename", "rename_all"]));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok((js_name, rename_all))
+}
+
+fn gen_string(variants: &[EnumVariant<'_>]) -> (Tokens, Tokens) {
+    let idents = variants
+        .iter()
+        .map(|variant| &variant.variant.ident)
+        .collect::<Vec<_>>();
+    let names = variants
+        .iter()
+        .map(|variant| &variant.js_name)
+        .collect::<Vec<_>>();
+    let expected = format!("one of {} expected", quote_names(&names));
+
+    let from_body = quote! {
+        let value = <String as FromNapi>::from_napi(value)?;
+
+        match value.as_str() {
+            #(#names => Ok(Self::#idents),)*
+            _ => {
+                let message = NapiString::from_str(env, #expected)?;
+                Err(NapiError::type_error(env, &message))
+            }
+        }
+    };
+
+    let to_body = quote! {
+        let name = match *self {
+            #(Self::#idents => #names,)*
+        };
+
+        ToNapi::to_napi(name, env)
+    };
+
+    (from_body, to_body)
+}
+
+fn gen_numeric(variants: &[EnumVariant<'_>]) -> (Tokens, Tokens) {
+    let idents = variants
+        .iter()
+        .map(|variant| &variant.variant.ident)
+        .collect::<Vec<_>>();
+
+    let from_body = quote! {
+        let value = <i64 as FromNapi>::from_napi(value)?;
+
+        #(
+            if value == Self::#idents as i64 {
+                return Ok(Self::#idents);
+            }
+        )*
+
+        let expected = [#(Self::#idents as i64),*]
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        let message = NapiString::from_str(
+            env,
+            &format!("one of {} expected", expected.join(", ")),
+        )?;
+        Err(NapiError::type_error(env, &message))
+    };
+
+    let to_body = quote! {
+        let value = match *self {
+            #(Self::#idents => Self::#idents as i64,)*
+        };
+
+        ToNapi::to_napi(&value, env)
+    };
+
+    (from_body, to_body)
+}
+
+fn gen_tagged(
+    variants: &[EnumVariant<'_>],
+    tag: &str,
+) -> Result<(Tokens, Tokens)> {
+    let names = variants
+        .iter()
+        .map(|variant| &variant.js_name)
+        .collect::<Vec<_>>();
+    let expected = format!("one of {} expected", quote_names(&names));
+
+    let mut from_arms = Vec::new();
+    let mut to_arms = Vec::new();
+
+    for variant in variants {
+        let ident = &variant.variant.ident;
+        let js_name = &variant.js_name;
+
+        let set_tag = quote! {
+            object.set_named_property(
+                #tag,
+                &ToNapi::to_napi(#js_name, env)?,
+            )?;
+        };
+
+        match variant.variant.fields {
+            Fields::Unit => {
+                from_arms.push(quote! { #js_name => Ok(Self::#ident) });
+                to_arms.push(quote! {
+                    Self::#ident => {
+                        let object = NapiObject::new(env)?;
+                        #set_tag
+                        Ok(object.as_napi_any())
+                    }
+                });
+            }
+            Fields::Named(ref fields) => {
+                let fields =
+                    parse_object_fields(&fields.named, variant.rename_all)?;
+                let from_fields = gen_from_object(&fields);
+                let bindings = gen_bindings(&fields);
+                let to_fields = gen_to_object(&fields, |ident| {
+                    quote! { *#ident }
+                });
+
+                from_arms.push(quote! {
+                    #js_name => Ok(Self::#ident { #from_fields })
+                });
+                to_arms.push(quote! {
+                    Self::#ident { #bindings } => {
+                        let object = NapiObject::new(env)?;
+                        #set_tag
+                        #to_fields
+                        Ok(object.as_napi_any())
+                    }
+                });
+            }
+            // The object the only field converts to gets the tag added, so
+            // it must be an object.
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed[0].ty;
+                let assert_object = quote_spanned! { ty.span() =>
+                    assert_object::<#ty>();
+                };
+
+                from_arms.push(quote! {
+                    #js_name => FromNapi::from_napi(value).map(Self::#ident)
+                });
+                to_arms.push(quote! {
+                    Self::#ident(ref inner) => {
+                        fn assert_object<T: ::napi::NapiObjectValue>() {}
+                        #assert_object
+
+                        let value = ToNapi::to_napi(inner, env)?;
+                        let object = value.as_object()?;
+                        #set_tag
+                        Ok(value)
+                    }
+                });
+            }
+            Fields::Unnamed(ref fields) => {
+                return Err(Error::new_spanned(
+                    fields,
+                    "several unnamed fields are only supported in \
+                     #[napi(untagged)] enums",
+                ));
+            }
+        }
+    }
+
+    let from_body = quote! {
+        let object = value.as_object()?;
+        let tag = object.get_named_property(#tag)?;
+        let tag = <String as FromNapi>::from_napi(tag)
+            .map_err(|error| error.field_context(#tag))?;
+
+        match tag.as_str() {
+            #(#from_arms,)*
+            _ => {
+                let message = NapiString::from_str(env, #expected)?;
+                Err(NapiError::type_error(env, &message)
+                    .field_context(#tag))
+            }
+        }
+    };
+
+    let to_body = quote! {
+        match *self {
+            #(#to_arms)*
+        }
+    };
+
+    Ok((from_body, to_body))
+}
+
+fn gen_untagged(
+    name: &Ident,
+    variants: &[EnumVariant<'_>],
+) -> Result<(Tokens, Tokens)> {
+    let expected = format!("{} expected", name);
+
+    let mut attempts = Vec::new();
+    let mut to_arms = Vec::new();
+
+    for variant in variants {
+        let ident = &variant.variant.ident;
+
+        match variant.variant.fields {
+            // Unit variants are represented by their names, as in enums
+            // with only unit variants, so that they can be told apart.
+            Fields::Unit => {
+                let js_name = &variant.js_name;
+
+                attempts.push(quote! {
+                    if let Ok(name) = <String as FromNapi>::from_napi(value) {
+                        if name == #js_name {
+                            return Ok(Self::#ident);
+                        }
+                    }
+                });
+                to_arms.push(quote! {
+                    Self::#ident => ToNapi::to_napi(#js_name, env)
+                });
+            }
+            Fields::Named(ref fields) => {
+                let fields =
+                    parse_object_fields(&fields.named, variant.rename_all)?;
+                let from_fields = gen_from_object(&fields);
+                let bindings = gen_bindings(&fields);
+                let to_fields = gen_to_object(&fields, |ident| {
+                    quote! { *#ident }
+                });
+
+                attempts.push(quote! {
+                    if let Ok(result) = value.as_object().and_then(|object| {
+                        Ok(Self::#ident { #from_fields })
+                    }) {
+                        return Ok(result);
+                    }
+                });
+                to_arms.push(quote! {
+                    Self::#ident { #bindings } => {
+                        let object = NapiObject::new(env)?;
+                        #to_fields
+                        Ok(object.as_napi_any())
+                    }
+                });
+            }
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                attempts.push(quote! {
+                    if let Ok(result) = FromNapi::from_napi(value) {
+                        return Ok(Self::#ident(result));
+                    }
+                });
+                to_arms.push(quote! {
+                    Self::#ident(ref inner) => ToNapi::to_napi(inner, env)
+                });
+            }
+            // Several unnamed fields are represented as an array.
+            Fields::Unnamed(ref fields) => {
+                let bindings = (0..fields.unnamed.len())
+                    .map(|idx| {
+                        Ident::new(&format!("field{}", idx), Span::call_site())
+                    })
+                    .collect::<Vec<_>>();
+                let placeholders = bindings.iter().map(|_| quote! { _ });
+                let indices = (0..bindings.len() as u32).collect::<Vec<_>>();
+                let len = bindings.len();
+
+                attempts.push(quote! {
+                    if let Ok((#(#bindings,)*)) =
+                        <(#(#placeholders,)*) as FromNapi>::from_napi(value)
+                    {
+                        return Ok(Self::#ident(#(#bindings),*));
+                    }
+                });
+                to_arms.push(quote! {
+                    Self::#ident(#(ref #bindings),*) => {
+                        let array = NapiArray::with_len(env, #len)?;
+                        #(
+                            array.set(
+                                #indices,
+                                &ToNapi::to_napi(#bindings, env)?,
+                            )?;
+                        )*
+                        Ok(array.as_napi_any())
+                    }
+                });
+            }
+        }
+    }
+
+    let from_body = quote! {
+        #(#attempts)*
+
+        let message = NapiString::from_str(env, #expected)?;
+        Err(NapiError::type_error(env, &message))
+    };
+
+    let to_body = quote! {
+        match *self {
+            #(#to_arms,)*
+        }
+    };
+
+    Ok((from_body, to_body))
+}
+
+fn ts_declaration(
+    name: &Ident,
+    repr: &Repr,
+    variants: &[EnumVariant<'_>],
+) -> Result<String> {
+    let tag = match *repr {
+        Repr::String => {
+            let names = variants
+                .iter()
+                .map(|variant| format!("{:?}", variant.js_name))
+                .collect::<Vec<_>>();
+            return Ok(format!(
+                "export type {} = {}\n",
+                name,
+                names.join(" | "),
+            ));
+        }
+        // The discriminants are numbered the same way in TypeScript.
+        Repr::Numeric => {
+            let members = variants
+                .iter()
+                .map(|variant| {
+                    let variant = variant.variant;
+                    let docs = ts_docs(&variant.attrs, "  ");
+                    match variant.discriminant {
+                        Some((_, ref value)) => format!(
+                            "{}  {} = {},\n",
+                            docs,
+                            variant.ident,
+                            quote!(#value),
+                        ),
+                        None => format!("{}  {},\n", docs, variant.ident),
+                    }
+                })
+                .collect::<String>();
+            return Ok(format!(
+                "export const enum {} {{\n{}}}\n",
+                name, members,
+            ));
+        }
+        Repr::Tagged(ref tag) => Some(tag),
+        Repr::Untagged => None,
+    };
+
+    let mut members = String::new();
+
+    for variant in variants {
+        let tag = tag.map(|tag| {
+            format!("{}: {:?}", ts_property(tag), variant.js_name)
+        });
+
+        let member = match variant.variant.fields {
+            Fields::Unit => match tag {
+                Some(tag) => format!("{{ {} }}", tag),
+                None => format!("{:?}", variant.js_name),
+            },
+            Fields::Named(ref fields) => {
+                let fields =
+                    parse_object_fields(&fields.named, variant.rename_all)?;
+                ts_object_type(tag, &fields)
+            }
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                let ty = ts_type(&fields.unnamed[0].ty);
+                match tag {
+                    Some(tag) => format!("{{ {} }} & {}", tag, ty),
+                    None => ty,
+                }
+            }
+            Fields::Unnamed(ref fields) => {
+                let types = fields
+                    .unnamed
+                    .iter()
+                    .map(|field| ts_type(&field.ty))
+                    .collect::<Vec<_>>();
+                format!("[{}]", types.join(", "))
+            }
+        };
+
+        members.push_str(&format!("\n  | {}", member));
+    }
+
+    Ok(format!("export type {} ={}\n", name, members))
+}
+
+fn quote_names(names: &[&String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("\"{}\"", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
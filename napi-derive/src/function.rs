@@ -0,0 +1,281 @@
+use proc_macro2::{Span, TokenStream as Tokens, TokenTree};
+use quote::ToTokens;
+use syn::{Data, DeriveInput, Error, FnArg, GenericParam, Ident, ItemFn,
+          Lifetime, LitStr, Pat, Path, Result, Type};
+
+use args::{impl_napi_args, ts_params};
+use case::to_camel_case;
+use diagnostics::unknown_attribute;
+use typedef::{gen_tracking, ts_docs, ts_return_type, write};
+
+// For `#[napi] fn foo(...)`, keeps the function as is and generates next to
+// it an argument struct deriving `NapiArgs`, an `extern "C"` wrapper, and a
+// `NapiExport` constant named `__napi_export_foo` that `napi_module!` uses to
+// register the function.
+pub fn impl_napi_fn(attr: Tokens, mut item: ItemFn) -> Result<Tokens> {
+    let mut js_name = None;
+
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("js_name") {
+            js_name = Some(meta.value()?.parse::<LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(unknown_attribute(&meta, &["js_name"]))
+        }
+    });
+    syn::parse::Parser::parse2(parser, attr)?;
+
+    let sig = &mut item.sig;
+    let fn_ident = sig.ident.clone();
+
+    if let Some(param) = sig
+        .generics
+        .params
+        .iter()
+        .find(|param| !matches!(param, GenericParam::Lifetime(_)))
+    {
+        return Err(Error::new_spanned(
+            param,
+            "#[napi] functions cannot have generic parameters",
+        ));
+    }
+
+    let mut has_context = false;
+    let mut fields = Vec::new();
+    let mut idents = Vec::new();
+    let mut types = Vec::new();
+
+    for (idx, input) in sig.inputs.iter_mut().enumerate() {
+        let input = match *input {
+            FnArg::Typed(ref mut input) => input,
+            FnArg::Receiver(ref receiver) => {
+                return Err(Error::new_spanned(
+                    receiver,
+                    "#[napi] functions cannot take `self`",
+                ));
+            }
+        };
+
+        if idx == 0 && is_context(&input.ty) {
+            has_context = true;
+            continue;
+        }
+
+        let ident = match *input.pat {
+            Pat::Ident(ref pat) => pat.ident.clone(),
+            ref pat => {
+                return Err(Error::new_spanned(
+                    pat,
+                    "#[napi] function arguments must be identifiers",
+                ));
+            }
+        };
+
+        // The `#[napi(...)]` attributes of the arguments are meant for the
+        // argument struct, and are not allowed on the function itself.
+        let (napi_attrs, other) = input
+            .attrs
+            .drain(..)
+            .partition::<Vec<_>, _>(|attr| attr.path().is_ident("napi"));
+        input.attrs = other;
+
+        let ty = &input.ty;
+        fields.push(quote! { #(#napi_attrs)* #ident: #ty });
+        idents.push(ident);
+        types.push(ty.clone());
+    }
+
+    let args_ident = prefixed_ident("__napi_args_", &fn_ident);
+    let wrapper_ident = prefixed_ident("__napi_callback_", &fn_ident);
+    let export_ident = prefixed_ident("__napi_export_", &fn_ident);
+
+    // Only the lifetimes that the arguments use can be declared on the
+    // struct.
+    let lifetimes = sig
+        .generics
+        .lifetimes()
+        .map(|param| &param.lifetime)
+        .filter(|lifetime| {
+            types.iter().any(|ty| mentions_lifetime(ty, lifetime))
+        })
+        .collect::<Vec<_>>();
+
+    // The `#[napi(...)]` attributes are only needed to derive `NapiArgs` and
+    // would resolve to this very macro if they were emitted.
+    let args_input: DeriveInput = syn::parse2(quote! {
+        struct #args_ident<#(#lifetimes),*> {
+            #(#fields),*
+        }
+    })?;
+    let args_impl = impl_napi_args(&args_input)?;
+    let args_struct = quote! {
+        struct #args_ident<#(#lifetimes),*> {
+            #(#idents: #types),*
+        }
+    };
+
+    let context = if has_context {
+        quote! { context, }
+    } else {
+        quote! {}
+    };
+    let js_name =
+        js_name.unwrap_or_else(|| to_camel_case(&fn_ident.to_string()));
+    let vis = &item.vis;
+
+    // Functions that only take the context read the arguments from it, so
+    // any number of them is accepted.
+    let reads_args = has_context && idents.is_empty();
+    let args = if reads_args {
+        quote! { #args_ident {} }
+    } else {
+        quote! { NapiArgs::from_context(context)? }
+    };
+
+    if let Data::Struct(ref data) = args_input.data {
+        let params = if reads_args {
+            "...args: unknown[]".to_owned()
+        } else {
+            ts_params(&data.fields)?
+        };
+
+        write(
+            "fn",
+            &fn_ident.to_string(),
+            &format!(
+                "{}export function {}({}): {}\n",
+                ts_docs(&item.attrs, ""),
+                js_name,
+                params,
+                ts_return_type(&item.sig.output),
+            ),
+        )?;
+    }
+
+    let tracking = gen_tracking();
+
+    Ok(quote! {
+        #item
+
+        #tracking
+
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #args_struct
+
+        #args_impl
+
+        #[doc(hidden)]
+        #[allow(non_snake_case, clippy::not_unsafe_ptr_arg_deref)]
+        extern "C" fn #wrapper_ident(
+            env: ::napi::sys::napi_env,
+            cb_info: ::napi::sys::napi_callback_info,
+        ) -> ::napi::sys::napi_value {
+            #[allow(unused_imports)]
+            use ::napi::{NapiArgs, NapiReturn, NapiValue};
+
+            ::napi::run_callback(env, cb_info, |context| {
+                let #args_ident { #(#idents),* } = #args;
+
+                #fn_ident(#context #(#idents),*)
+                    .into_napi(context)
+                    .map(|value| value.as_sys_value())
+            })
+        }
+
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        #vis const #export_ident: ::napi::NapiExport = ::napi::NapiExport {
+            name: #js_name,
+            callback: #wrapper_ident,
+        };
+    })
+}
+
+// For `napi_module!(foo, bar::baz)`, generates the module initialization
+// function that exports the given `#[napi]` functions.
+pub fn impl_napi_module<I>(paths: I) -> Result<Tokens>
+where
+    I: Iterator<Item = Path>,
+{
+    let mut names = String::new();
+
+    let exports = paths
+        .map(|mut path| {
+            if let Some(segment) = path.segments.last_mut() {
+                names.push_str(&format!("{}\n", segment.ident));
+                segment.ident =
+                    prefixed_ident("__napi_export_", &segment.ident);
+            }
+            path
+        })
+        .collect::<Vec<_>>();
+
+    // Only the listed functions are declared.
+    write("module", "exports", &names)?;
+
+    let tracking = gen_tracking();
+
+    Ok(quote! {
+        #tracking
+
+        #[no_mangle]
+        #[allow(clippy::not_unsafe_ptr_arg_deref)]
+        pub extern "C" fn napi_register_module_v1(
+            env: ::napi::sys::napi_env,
+            exports: ::napi::sys::napi_value,
+        ) -> ::napi::sys::napi_value {
+            ::napi::register_module(env, exports, &[#(#exports),*])
+        }
+    })
+}
+
+// Whether the type of the first argument is `&NapiEnv` or `&CallContext`,
+// in which case the context of the call is passed in it.
+fn is_context(ty: &Type) -> bool {
+    let elem = match *ty {
+        Type::Reference(ref reference) => &reference.elem,
+        _ => return false,
+    };
+
+    match **elem {
+        Type::Path(ref path) => path.path.segments.last().is_some_and(
+            |segment| {
+                segment.ident == "NapiEnv" || segment.ident == "CallContext"
+            },
+        ),
+        _ => false,
+    }
+}
+
+fn mentions_lifetime(ty: &Type, lifetime: &Lifetime) -> bool {
+    fn walk(tokens: Tokens, lifetime: &Lifetime) -> bool {
+        let mut tokens = tokens.into_iter().peekable();
+
+        while let Some(token) = tokens.next() {
+            match token {
+                TokenTree::Punct(ref punct) if punct.as_char() == '\'' => {
+                    if let Some(TokenTree::Ident(ref ident)) = tokens.peek() {
+                        if *ident == lifetime.ident {
+                            return true;
+                        }
+                    }
+                }
+                TokenTree::Group(ref group)
+                    if walk(group.stream(), lifetime) =>
+                {
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
+        false
+    }
+
+    walk(ty.into_token_stream(), lifetime)
+}
+
+fn prefixed_ident(prefix: &str, ident: &Ident) -> Ident {
+    Ident::new(&format!("{}{}", prefix, ident), Span::call_site())
+}
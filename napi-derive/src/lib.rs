@@ -1,143 +1,66 @@
-#![recursion_limit = "128"]
-
 extern crate proc_macro;
+extern crate proc_macro2;
 #[macro_use]
 extern crate quote;
+#[macro_use]
 extern crate syn;
 
+mod args;
+mod case;
+mod diagnostics;
+mod enums;
+mod function;
+mod object;
+mod typedef;
+
 use proc_macro::TokenStream;
-use quote::Tokens;
-use syn::{Body, DeriveInput, VariantData};
+use syn::punctuated::Punctuated;
+use syn::{DeriveInput, ItemFn, Path, Token};
 
-#[proc_macro_derive(NapiArgs)]
+#[proc_macro_derive(NapiArgs, attributes(napi))]
 pub fn napi_args(input: TokenStream) -> TokenStream {
-    let ast = syn::parse_derive_input(&input.to_string()).unwrap();
-    match impl_napi_args(&ast) {
-        Ok(generated) => generated.parse().unwrap(),
-        Err(message) => panic!(message),
-    }
+    let ast = parse_macro_input!(input as DeriveInput);
+    args::impl_napi_args(&ast)
+        .unwrap_or_else(diagnostics::into_compile_error)
+        .into()
 }
 
-fn impl_napi_args(ast: &DeriveInput) -> Result<Tokens, &'static str> {
-    let name = &ast.ident;
-
-    let variant_data = match ast.body {
-        Body::Struct(ref data) => data,
-        _ => return Err("NapiArgs can only be derived for structs"),
-    };
-
-    let count = args_count(variant_data);
-    let (init_list, imports) = gen_args_code(variant_data);
-
-    let initializer = if let Some(init_list) = init_list {
-        quote! { #name #init_list }
-    } else {
-        quote! { #name }
-    };
-
-    let (gen_lifetime, ref_lifetime) = if count > 0 {
-        (quote! { <'env> }, quote! { 'env })
-    } else {
-        (quote!{}, quote!{})
-    };
-
-    Ok(quote! {
-        impl<'env> ::napi::NapiArgs<'env> for #name #gen_lifetime {
-            fn from_cb_info(
-                env: & #ref_lifetime ::napi::NapiEnv,
-                cb_info: ::napi::sys::napi_callback_info,
-            ) -> ::napi::NapiResult<Self> {
-                use ::napi::sys;
-                use ::napi::{NapiError, NapiString};
-
-                use ::std::ptr;
-
-                #imports
-
-                let mut argc = #count;
-                let mut argv = [ptr::null_mut(); #count];
-
-                env.handle_status(unsafe {
-                    sys::napi_get_cb_info(
-                        env.as_sys_env(),
-                        cb_info,
-                        &mut argc,
-                        argv.as_mut_ptr(),
-                        ptr::null_mut(),
-                        ptr::null_mut(),
-                    )
-                })?;
-
-                if argc != #count {
-                    let message = NapiString::from_str(env, &format!(
-                        "Expected {} arguments, but got {}",
-                        #count,
-                        argc,
-                    ))?;
-                    return Err(NapiError::type_error(env, &message));
-                }
-
-                Ok(#initializer)
-            }
-        }
-    })
+#[proc_macro_derive(NapiObject, attributes(napi))]
+pub fn napi_object(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    object::impl_napi_object(&ast)
+        .unwrap_or_else(diagnostics::into_compile_error)
+        .into()
 }
 
-fn args_count(variant_data: &VariantData) -> usize {
-    match *variant_data {
-        VariantData::Struct(ref fields) | VariantData::Tuple(ref fields) => {
-            fields.len()
-        }
-        VariantData::Unit => 0,
-    }
+#[proc_macro_derive(NapiEnum, attributes(napi))]
+pub fn napi_enum(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    enums::impl_napi_enum(&ast)
+        .unwrap_or_else(diagnostics::into_compile_error)
+        .into()
 }
 
-fn gen_args_code(
-    variant_data: &VariantData,
-) -> (Option<Tokens>, Option<Tokens>) {
-    match *variant_data {
-        VariantData::Struct(ref fields) => {
-            let inner = fields
-                .iter()
-                .enumerate()
-                .map(|(idx, field)| {
-                    let ident = field.clone().ident.unwrap();
-                    quote! {
-                        #ident: <_ as NapiValue>::from_sys_checked(
-                            env,
-                            argv[#idx],
-                        )?
-                    }
-                })
-                .collect::<Vec<_>>();
-
-            let outer = quote! {
-                { #(#inner),* }
-            };
-
-            let imports = quote! {
-                use ::napi::NapiValue;
-            };
-
-            (Some(outer), Some(imports))
-        }
-
-        VariantData::Tuple(ref fields) => {
-            let inner = (0..fields.len())
-                .map(|idx| {
-                    quote! {
-                        <_ as NapiValue>::from_sys_checked(env, argv[#idx])?
-                    }
-                })
-                .collect::<Vec<_>>();
-
-            let outer = quote! {
-                ( #(#inner),* )
-            };
-
-            (Some(outer), None)
-        }
+#[proc_macro_attribute]
+pub fn napi(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(item as ItemFn);
+
+    // The function is kept on errors, so that they are not followed by
+    // errors about it missing.
+    function::impl_napi_fn(attr.into(), item.clone())
+        .unwrap_or_else(|error| {
+            let error = diagnostics::into_compile_error(error);
+            quote! { #error #item }
+        })
+        .into()
+}
 
-        VariantData::Unit => (None, None),
-    }
+#[proc_macro]
+pub fn napi_module(input: TokenStream) -> TokenStream {
+    let paths = parse_macro_input!(
+        input with Punctuated::<Path, Token![,]>::parse_terminated
+    );
+    function::impl_napi_module(paths.into_iter())
+        .unwrap_or_else(diagnostics::into_compile_error)
+        .into()
 }
@@ -0,0 +1,342 @@
+use proc_macro2::TokenStream as Tokens;
+use syn::{Attribute, Data, DeriveInput, Error, Expr, Field, Fields, Ident,
+          LitStr, Result};
+
+use args::is_option;
+use case::RenameRule;
+use diagnostics::{check_field_type, check_generics, rename_rule,
+                  unknown_attribute};
+use typedef::{gen_tracking, ts_docs, ts_property, ts_type, write};
+
+enum FieldKind {
+    Required,
+    Optional,
+    Default(Option<Expr>),
+    Skip,
+}
+
+pub struct ObjectField<'a> {
+    field: &'a Field,
+    ident: &'a Ident,
+    js_name: String,
+    kind: FieldKind,
+}
+
+pub fn impl_napi_object(ast: &DeriveInput) -> Result<Tokens> {
+    let name = &ast.ident;
+    let rename_all = parse_rename_all(&ast.attrs)?;
+
+    check_generics(&ast.generics, "NapiObject")?;
+
+    let fields = match ast.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => {
+                parse_object_fields(&fields.named, rename_all)?
+            }
+            Fields::Unit => Vec::new(),
+            Fields::Unnamed(ref fields) => {
+                return Err(Error::new_spanned(
+                    fields,
+                    "NapiObject can only be derived for structs with named \
+                     fields",
+                ));
+            }
+        },
+        Data::Enum(ref data) => {
+            return Err(Error::new_spanned(
+                data.enum_token,
+                "NapiObject can only be derived for structs, use NapiEnum \
+                 for enums",
+            ));
+        }
+        Data::Union(ref data) => {
+            return Err(Error::new_spanned(
+                data.union_token,
+                "NapiObject can only be derived for structs",
+            ));
+        }
+    };
+
+    let from_fields = gen_from_object(&fields);
+    let to_fields = gen_to_object(&fields, |ident| quote! { self.#ident });
+
+    let gen_lifetime = if ast.generics.lifetimes().count() > 0 {
+        quote! { <'env> }
+    } else {
+        quote! {}
+    };
+
+    write(
+        "type",
+        &name.to_string(),
+        &format!(
+            "{}export interface {} {{\n{}}}\n",
+            ts_docs(&ast.attrs, ""),
+            name,
+            ts_interface_body(&fields),
+        ),
+    )?;
+
+    let tracking = gen_tracking();
+
+    Ok(quote! {
+        #tracking
+
+        impl<'env> ::napi::FromNapi<'env> for #name #gen_lifetime {
+            fn from_napi(
+                value: ::napi::NapiAny<'env>,
+            ) -> ::napi::NapiResult<Self> {
+                #[allow(unused_imports)]
+                use ::napi::{FromNapi, NapiValue, NapiValueType};
+
+                #[allow(unused_variables)]
+                let env = value.env();
+                #[allow(unused_variables)]
+                let object = value.as_object()?;
+
+                Ok(Self { #from_fields })
+            }
+        }
+
+        impl<'env> ::napi::ToNapi<'env> for #name #gen_lifetime {
+            fn to_napi(
+                &self,
+                env: &'env ::napi::NapiEnv,
+            ) -> ::napi::NapiResult<::napi::NapiAny<'env>> {
+                #[allow(unused_imports)]
+                use ::napi::{NapiValue, ToNapi};
+
+                let object = ::napi::NapiObject::new(env)?;
+
+                #to_fields
+
+                Ok(object.as_napi_any())
+            }
+        }
+
+        impl<'env> ::napi::NapiObjectValue for #name #gen_lifetime {}
+    })
+}
+
+pub fn parse_object_fields<'a, I>(
+    fields: I,
+    rename_all: Option<RenameRule>,
+) -> Result<Vec<ObjectField<'a>>>
+where
+    I: IntoIterator<Item = &'a Field>,
+{
+    let mut result = Vec::new();
+
+    for field in fields {
+        check_field_type(&field.ty)?;
+
+        let ident = match field.ident {
+            Some(ref ident) => ident,
+            None => {
+                return Err(Error::new_spanned(field, "expected a named field"));
+            }
+        };
+        let mut kind = if is_option(&field.ty) {
+            FieldKind::Optional
+        } else {
+            FieldKind::Required
+        };
+        let mut js_name = match rename_all {
+            Some(rule) => rule.apply(&ident.to_string()),
+            None => ident.to_string(),
+        };
+
+        let attrs =
+            field.attrs.iter().filter(|attr| attr.path().is_ident("napi"));
+
+        for attr in attrs {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    js_name = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("skip") {
+                    kind = FieldKind::Skip;
+                } else if meta.path.is_ident("default") {
+                    kind = if meta.input.peek(syn::Token![=]) {
+                        FieldKind::Default(Some(meta.value()?.parse()?))
+                    } else {
+                        FieldKind::Default(None)
+                    };
+                } else {
+                    return Err(unknown_attribute(
+                        &meta,
+                        &["rename", "skip", "default"],
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+
+        result.push(ObjectField {
+            field,
+            ident,
+            js_name,
+            kind,
+        });
+    }
+
+    Ok(result)
+}
+
+// Generates the pattern binding by reference the fields that are converted
+// to JavaScript.
+pub fn gen_bindings(fields: &[ObjectField<'_>]) -> Tokens {
+    let bindings = fields.iter().map(|field| {
+        let ident = field.ident;
+        match field.kind {
+            FieldKind::Skip => quote! { #ident: _ },
+            _ => quote! { ref #ident },
+        }
+    });
+
+    quote! { #(#bindings),* }
+}
+
+// Generates the field initializers of a struct literal reading the fields
+// from `object`.
+pub fn gen_from_object(fields: &[ObjectField<'_>]) -> Tokens {
+    let values = fields.iter().map(|field| {
+        let ident = field.ident;
+        let js_name = &field.js_name;
+
+        let conversion = quote! {
+            <_ as FromNapi>::from_napi(value)
+                .map_err(|error| error.field_context(#js_name))?
+        };
+
+        let value = match field.kind {
+            FieldKind::Required | FieldKind::Optional => quote! {
+                {
+                    let value = object.get_named_property(#js_name)?;
+                    #conversion
+                }
+            },
+            FieldKind::Default(ref default) => {
+                let default = match *default {
+                    Some(ref expr) => quote! { #expr },
+                    None => quote! { ::std::default::Default::default() },
+                };
+
+                quote! {
+                    {
+                        let value = object.get_named_property(#js_name)?;
+                        if value.value_type()? == NapiValueType::Undefined {
+                            #default
+                        } else {
+                            #conversion
+                        }
+                    }
+                }
+            }
+            FieldKind::Skip => quote! { ::std::default::Default::default() },
+        };
+
+        quote! { #ident: #value }
+    });
+
+    quote! { #(#values),* }
+}
+
+// Generates the statements setting the fields on `object`, where `place`
+// gives the expression for accessing a field.
+pub fn gen_to_object<F>(fields: &[ObjectField<'_>], place: F) -> Tokens
+where
+    F: Fn(&Ident) -> Tokens,
+{
+    let statements = fields.iter().map(|field| {
+        let js_name = &field.js_name;
+        let place = place(field.ident);
+
+        let set_value = quote! {
+            object.set_named_property(
+                #js_name,
+                &ToNapi::to_napi(value, env)
+                    .map_err(|error| error.field_context(#js_name))?,
+            )?;
+        };
+
+        // Missing optional fields are left out rather than set to `null`.
+        match field.kind {
+            FieldKind::Optional => quote! {
+                if let Some(ref value) = #place {
+                    #set_value
+                }
+            },
+            FieldKind::Skip => quote! {},
+            _ => quote! {
+                let value = &#place;
+                #set_value
+            },
+        }
+    });
+
+    quote! { #(#statements)* }
+}
+
+// Renders the properties of an interface, one per line along with their
+// docs.
+fn ts_interface_body(fields: &[ObjectField<'_>]) -> String {
+    fields
+        .iter()
+        .filter_map(|field| {
+            field.ts_declaration().map(|declaration| {
+                format!(
+                    "{}  {}\n",
+                    ts_docs(&field.field.attrs, "  "),
+                    declaration,
+                )
+            })
+        })
+        .collect()
+}
+
+// Renders the properties of an object type on a single line.
+pub fn ts_object_type(
+    tag: Option<String>,
+    fields: &[ObjectField<'_>],
+) -> String {
+    let properties = tag
+        .into_iter()
+        .chain(fields.iter().filter_map(ObjectField::ts_declaration))
+        .collect::<Vec<_>>();
+
+    format!("{{ {} }}", properties.join("; "))
+}
+
+impl<'a> ObjectField<'a> {
+    fn ts_declaration(&self) -> Option<String> {
+        let name = ts_property(&self.js_name);
+        let ty = ts_type(&self.field.ty);
+
+        match self.kind {
+            FieldKind::Required => Some(format!("{}: {}", name, ty)),
+            FieldKind::Optional | FieldKind::Default(_) => {
+                Some(format!("{}?: {}", name, ty))
+            }
+            FieldKind::Skip => None,
+        }
+    }
+}
+
+pub fn parse_rename_all(
+    attrs: &[Attribute],
+) -> Result<Option<RenameRule>> {
+    let mut rename_all = None;
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("napi")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                rename_all = Some(rename_rule(&meta)?);
+            } else {
+                return Err(unknown_attribute(&meta, &["rename_all"]));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(rename_all)
+}
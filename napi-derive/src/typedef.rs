@@ -0,0 +1,331 @@
+use std::collections::hash_map::DefaultHasher;
+use std::env;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+use proc_macro2::{Span, TokenStream as Tokens};
+use syn::{Attribute, Error, Expr, ExprLit, GenericArgument, Lit, Meta,
+          PathArguments, Result, ReturnType, Type};
+
+// The directory that the TypeScript declarations of the expanded items are
+// written to, set by `napi-typegen`.
+const TYPE_DEF_DIR: &str = "NAPI_TYPE_DEF_DIR";
+
+// Generates a reference to the variable, so that Cargo rebuilds the crate,
+// and thus writes the declarations again, whenever it changes.
+pub fn gen_tracking() -> Tokens {
+    quote! {
+        const _: ::std::option::Option<&str> =
+            option_env!("NAPI_TYPE_DEF_DIR");
+    }
+}
+
+// Writes the declaration of an item named `name` of the given kind (`type`,
+// `fn` or `module`), if the declarations are being generated.  Items of the
+// same name in different modules are told apart by a hash of their
+// declarations, so that `napi-typegen` can report the conflict.
+pub fn write(kind: &str, name: &str, contents: &str) -> Result<()> {
+    let dir = match env::var_os(TYPE_DEF_DIR) {
+        Some(dir) => PathBuf::from(dir),
+        None => return Ok(()),
+    };
+    let krate = env::var("CARGO_CRATE_NAME").unwrap_or_default();
+    let mut hasher = DefaultHasher::new();
+    contents.hash(&mut hasher);
+    let path = dir.join(format!(
+        "{}.{}.{}.{:016x}.d.ts",
+        krate,
+        kind,
+        name,
+        hasher.finish(),
+    ));
+
+    fs::write(&path, contents).map_err(|error| {
+        Error::new(
+            Span::call_site(),
+            format!(
+                "failed to write type definitions to {}: {}",
+                path.display(),
+                error,
+            ),
+        )
+    })
+}
+
+// Renders the doc comments as a JSDoc comment.
+pub fn ts_docs(attrs: &[Attribute], indent: &str) -> String {
+    let mut lines = Vec::new();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("doc")) {
+        if let Meta::NameValue(ref meta) = attr.meta {
+            if let Expr::Lit(ExprLit {
+                lit: Lit::Str(ref doc),
+                ..
+            }) = meta.value
+            {
+                for line in doc.value().split('\n') {
+                    let line = line.strip_prefix(' ').unwrap_or(line);
+                    lines.push(line.replace("*/", "*\\/"));
+                }
+            }
+        }
+    }
+
+    match lines.len() {
+        0 => String::new(),
+        1 => format!("{}/** {} */\n", indent, lines[0]),
+        _ => {
+            let mut docs = format!("{}/**\n", indent);
+            for line in lines {
+                if line.is_empty() {
+                    docs.push_str(&format!("{} *\n", indent));
+                } else {
+                    docs.push_str(&format!("{} * {}\n", indent, line));
+                }
+            }
+            docs.push_str(&format!("{} */\n", indent));
+            docs
+        }
+    }
+}
+
+// Renders a property name, quoting it unless it is a valid identifier.
+pub fn ts_property(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_ident = chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$');
+
+    if is_ident {
+        name.to_owned()
+    } else {
+        format!("{:?}", name)
+    }
+}
+
+// The type of the value returned from a function to JavaScript.
+pub fn ts_return_type(output: &ReturnType) -> String {
+    let ty = match *output {
+        ReturnType::Default => return "void".to_owned(),
+        ReturnType::Type(_, ref ty) => ty,
+    };
+    let ty = match result_type(ty) {
+        Some(ty) => ty,
+        None => ty,
+    };
+
+    match *ty {
+        Type::Tuple(ref tuple) if tuple.elems.is_empty() => "void".to_owned(),
+        _ => ts_type(ty),
+    }
+}
+
+// The TypeScript type that values of a Rust type are converted to, which is
+// also used for the fields of objects, since those go both ways.  The mapping
+// goes by the names of the types, and any type that is not known is assumed
+// to be an object or an enum declared under the same name.
+pub fn ts_type(ty: &Type) -> String {
+    ts_type_in(ty, false)
+}
+
+// The TypeScript type of an argument of a Rust type, which is wider than
+// `ts_type` where more is accepted than returned: 64-bit integers can also be
+// passed as BigInts.
+pub fn ts_arg_type(ty: &Type) -> String {
+    ts_type_in(ty, true)
+}
+
+fn ts_type_in(ty: &Type, arg: bool) -> String {
+    let path = match *ty {
+        Type::Path(ref path) if path.qself.is_none() => &path.path,
+        Type::Reference(ref reference) => {
+            return ts_type_in(&reference.elem, arg);
+        }
+        Type::Slice(ref slice) => return ts_array(&slice.elem, arg),
+        Type::Array(ref array) => return ts_array(&array.elem, arg),
+        Type::Tuple(ref tuple) if tuple.elems.is_empty() => {
+            return "undefined".to_owned();
+        }
+        Type::Tuple(ref tuple) => {
+            let elems = tuple
+                .elems
+                .iter()
+                .map(|elem| ts_type_in(elem, arg))
+                .collect::<Vec<_>>();
+            return format!("[{}]", elems.join(", "));
+        }
+        Type::Paren(ref paren) => return ts_type_in(&paren.elem, arg),
+        Type::Group(ref group) => return ts_type_in(&group.elem, arg),
+        _ => return "unknown".to_owned(),
+    };
+
+    let segment = match path.segments.last() {
+        Some(segment) => segment,
+        None => return "unknown".to_owned(),
+    };
+    let args = type_arguments(&segment.arguments);
+    let name = segment.ident.to_string();
+
+    let ts = match (name.as_str(), args.as_slice()) {
+        ("bool", _) | ("NapiBoolean", _) => "boolean",
+        ("i64", _) | ("isize", _) | ("u64", _) | ("usize", _) if arg => {
+            "number | bigint"
+        }
+        ("i8", _) | ("i16", _) | ("i32", _) | ("i64", _) | ("isize", _)
+        | ("u8", _) | ("u16", _) | ("u32", _) | ("u64", _)
+        | ("usize", _) | ("f32", _) | ("f64", _) | ("NapiNumber", _) => {
+            "number"
+        }
+        ("i128", _) | ("u128", _) | ("NapiBigInt", _) => "bigint",
+        ("String", _) | ("str", _) | ("NapiString", _) => "string",
+        ("Vec", [elem]) => return ts_array(elem, arg),
+        ("Option", [inner]) => {
+            return format!("{} | null", ts_type_in(inner, arg));
+        }
+        ("HashMap", [_, value, ..]) | ("BTreeMap", [_, value]) => {
+            return format!("Record<string, {}>", ts_type_in(value, arg));
+        }
+        ("NapiResult", [inner]) | ("Result", [inner, ..]) => {
+            return ts_type_in(inner, arg);
+        }
+        ("NapiAny", _) => "unknown",
+        ("NapiNull", _) => "null",
+        ("NapiUndefined", _) => "undefined",
+        ("NapiObject", _) | ("NapiExternal", _) => "object",
+        ("NapiArray", _) => "unknown[]",
+        ("NapiFunction", _) => "(...args: any[]) => any",
+        ("NapiSymbol", _) => "symbol",
+        ("NapiBuffer", _) => "Buffer",
+        ("NapiArrayBuffer", _) => "ArrayBuffer",
+        ("NapiTypedArray", _) => "ArrayBufferView",
+        ("NapiDataView", _) => "DataView",
+        ("NapiDate", _) => "Date",
+        ("NapiPromise", _) => "Promise<unknown>",
+        ("NapiErrorObject", _) => "Error",
+        _ => return name,
+    };
+
+    ts.to_owned()
+}
+
+// Byte vectors are converted to buffers, and everything else to arrays.
+fn ts_array(elem: &Type, arg: bool) -> String {
+    match *elem {
+        Type::Path(ref path) if path.path.is_ident("u8") => {
+            return "Buffer".to_owned();
+        }
+        _ => {}
+    }
+
+    let elem = ts_type_in(elem, arg);
+    if elem.contains(' ') {
+        format!("({})[]", elem)
+    } else {
+        format!("{}[]", elem)
+    }
+}
+
+// The type that a `Result` or `NapiResult` is of.
+fn result_type(ty: &Type) -> Option<&Type> {
+    let segment = match *ty {
+        Type::Path(ref path) => path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != "Result" && segment.ident != "NapiResult" {
+        return None;
+    }
+
+    type_arguments(&segment.arguments).first().cloned()
+}
+
+fn type_arguments(arguments: &PathArguments) -> Vec<&Type> {
+    match *arguments {
+        PathArguments::AngleBracketed(ref arguments) => arguments
+            .args
+            .iter()
+            .filter_map(|arg| match *arg {
+                GenericArgument::Type(ref ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ts(ty: &str) -> String {
+        ts_type(&syn::parse_str(ty).unwrap())
+    }
+
+    #[test]
+    fn maps_primitives() {
+        assert_eq!(ts("bool"), "boolean");
+        assert_eq!(ts("u32"), "number");
+        assert_eq!(ts("f64"), "number");
+        assert_eq!(ts("i128"), "bigint");
+        assert_eq!(ts("String"), "string");
+        assert_eq!(ts("&str"), "string");
+        assert_eq!(ts("()"), "undefined");
+    }
+
+    #[test]
+    fn maps_containers() {
+        assert_eq!(ts("Vec<u8>"), "Buffer");
+        assert_eq!(ts("&[u8]"), "Buffer");
+        assert_eq!(ts("Vec<String>"), "string[]");
+        assert_eq!(ts("Vec<Option<f64>>"), "(number | null)[]");
+        assert_eq!(ts("[u32; 4]"), "number[]");
+        assert_eq!(ts("Option<String>"), "string | null");
+        assert_eq!(ts("(u32, String)"), "[number, string]");
+        assert_eq!(
+            ts("HashMap<String, Vec<bool>>"),
+            "Record<string, boolean[]>"
+        );
+        assert_eq!(ts("BTreeMap<String, u8>"), "Record<string, number>");
+        assert_eq!(ts("NapiResult<String>"), "string");
+        assert_eq!(ts("Result<u32, Error>"), "number");
+    }
+
+    #[test]
+    fn maps_napi_values() {
+        assert_eq!(ts("NapiString<'env>"), "string");
+        assert_eq!(ts("napi::NapiObject<'env>"), "object");
+        assert_eq!(ts("NapiBuffer<'env>"), "Buffer");
+        assert_eq!(ts("NapiAny<'env>"), "unknown");
+    }
+
+    #[test]
+    fn widens_64_bit_integers_in_arguments() {
+        let arg = |ty: &str| ts_arg_type(&syn::parse_str(ty).unwrap());
+
+        assert_eq!(ts("i64"), "number");
+        assert_eq!(ts("Vec<usize>"), "number[]");
+        assert_eq!(arg("i64"), "number | bigint");
+        assert_eq!(arg("u64"), "number | bigint");
+        assert_eq!(arg("isize"), "number | bigint");
+        assert_eq!(arg("usize"), "number | bigint");
+        assert_eq!(arg("u32"), "number");
+        assert_eq!(arg("Option<u64>"), "number | bigint | null");
+        assert_eq!(arg("Vec<i64>"), "(number | bigint)[]");
+        assert_eq!(arg("&[u64]"), "(number | bigint)[]");
+        assert_eq!(arg("(i64, String)"), "[number | bigint, string]");
+        assert_eq!(
+            arg("HashMap<String, u64>"),
+            "Record<string, number | bigint>"
+        );
+    }
+
+    #[test]
+    fn refers_to_unknown_types_by_name() {
+        assert_eq!(ts("Options"), "Options");
+        assert_eq!(ts("config::Options"), "Options");
+        assert_eq!(ts("Vec<Options>"), "Options[]");
+        assert_eq!(ts("fn()"), "unknown");
+    }
+}
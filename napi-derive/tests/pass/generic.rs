@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate napi_derive;
+
+extern crate napi;
+
+use napi::NapiArray;
+
+#[derive(NapiArgs)]
+struct Args<'env, T> {
+    array: NapiArray<'env>,
+    #[napi(rest)]
+    items: Vec<T>,
+}
+
+fn main() {}
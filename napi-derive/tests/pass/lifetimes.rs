@@ -0,0 +1,15 @@
+#[macro_use]
+extern crate napi_derive;
+
+extern crate napi;
+
+use napi::NapiAny;
+
+#[derive(NapiArgs)]
+struct Lt<'a, 'b> {
+    x: NapiAny<'a>,
+    #[napi(rest)]
+    y: Vec<NapiAny<'b>>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+extern crate trybuild;
+
+// The diagnostics of the derives, compared against the `.stderr` files next
+// to the cases, and the inputs in `pass` that they must accept.
+// `TRYBUILD=overwrite cargo test` updates the diagnostics.
+#[test]
+fn ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+    cases.pass("tests/pass/*.rs");
+}
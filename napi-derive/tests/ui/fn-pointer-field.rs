@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate napi_derive;
+
+#[derive(NapiEnum)]
+#[napi(untagged)]
+enum Callback {
+    Fn(fn()),
+}
+
+fn main() {}
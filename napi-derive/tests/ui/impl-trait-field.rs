@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate napi_derive;
+
+#[derive(NapiArgs)]
+struct Args {
+    callback: impl Fn(),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate napi_derive;
+
+#[derive(NapiObject)]
+struct Options {
+    data: *const u8,
+}
+
+fn main() {}
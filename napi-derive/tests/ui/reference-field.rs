@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate napi_derive;
+
+#[derive(NapiObject)]
+struct Options<'a> {
+    label: &'a str,
+}
+
+fn main() {}
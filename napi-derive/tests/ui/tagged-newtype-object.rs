@@ -0,0 +1,13 @@
+#[macro_use]
+extern crate napi_derive;
+
+extern crate napi;
+
+use napi::NapiObject;
+
+#[derive(NapiEnum)]
+enum Setting<'env> {
+    Raw(NapiObject<'env>),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[macro_use]
+extern crate napi_derive;
+
+extern crate napi;
+
+#[derive(NapiEnum)]
+enum Setting {
+    Count(u32),
+}
+
+fn main() {}
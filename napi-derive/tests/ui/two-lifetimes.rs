@@ -0,0 +1,12 @@
+#[macro_use]
+extern crate napi_derive;
+
+extern crate napi;
+
+#[derive(NapiObject)]
+struct Options<'a, 'b> {
+    first: napi::NapiObject<'a>,
+    second: napi::NapiObject<'b>,
+}
+
+fn main() {}
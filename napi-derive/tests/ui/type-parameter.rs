@@ -0,0 +1,9 @@
+#[macro_use]
+extern crate napi_derive;
+
+#[derive(NapiEnum)]
+enum Value<T> {
+    Some(T),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate napi_derive;
+
+#[derive(NapiObject)]
+struct Options {
+    #[napi(renmae = "tag")]
+    label: String,
+}
+
+fn main() {}
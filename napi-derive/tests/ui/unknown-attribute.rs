@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate napi_derive;
+
+#[derive(NapiObject)]
+struct Options {
+    #[napi(optional)]
+    label: String,
+}
+
+fn main() {}
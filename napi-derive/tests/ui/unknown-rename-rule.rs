@@ -0,0 +1,10 @@
+#[macro_use]
+extern crate napi_derive;
+
+#[derive(NapiEnum)]
+#[napi(rename_all = "camel")]
+enum Color {
+    Red,
+}
+
+fn main() {}
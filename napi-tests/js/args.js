@@ -0,0 +1,43 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// Optional arguments are `None` when missing, `undefined` or `null`.
+assert.strictEqual(addon.optional(), 'none');
+assert.strictEqual(addon.optional(undefined), 'none');
+assert.strictEqual(addon.optional(null), 'none');
+assert.strictEqual(addon.optional(1.5), 'some 1.5');
+assert.throws(() => addon.optional('1.5'), {
+  name: 'TypeError',
+  message: 'argument 1 `value`: number expected',
+});
+assert.throws(() => addon.optional(1, 2), {
+  name: 'TypeError',
+  message: 'Expected from 0 to 1 arguments, but got 2',
+});
+
+// Arguments with a default take it when missing or `undefined` only.
+assert.strictEqual(addon.repeat('ab'), 'abab');
+assert.strictEqual(addon.repeat('ab', undefined, '-'), 'ab-ab');
+assert.strictEqual(addon.repeat('ab', 3, ', '), 'ab, ab, ab');
+assert.throws(() => addon.repeat(), {
+  name: 'TypeError',
+  message: 'Expected from 1 to 3 arguments, but got 0',
+});
+assert.throws(() => addon.repeat('ab', null), {
+  name: 'TypeError',
+  message: 'argument 2 `times`: number expected',
+});
+
+// Rest arguments collect the others, and are numbered as they are passed.
+assert.strictEqual(addon.sumFrom(1), 1);
+assert.strictEqual(addon.sumFrom(1, 2, 3), 6);
+assert.throws(() => addon.sumFrom(), {
+  name: 'TypeError',
+  message: 'Expected at least 1 arguments, but got 0',
+});
+assert.throws(() => addon.sumFrom(1, 2, '3'), {
+  name: 'TypeError',
+  message: 'argument 3 `numbers`: number expected',
+});
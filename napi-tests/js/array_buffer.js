@@ -0,0 +1,37 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+const buffer = new Uint8Array([1, 2, 3, 4]).buffer;
+const nodeBuffer = Buffer.from(buffer, 1, 3);
+const array = new Uint8Array(buffer, 1, 2);
+const view = new DataView(buffer, 2);
+
+assert.deepStrictEqual(
+  addon.arrayBufferBytes(buffer),
+  Buffer.from([1, 2, 3, 4])
+);
+assert.deepStrictEqual(addon.bufferBytes(nodeBuffer), Buffer.from([2, 3, 4]));
+assert.deepStrictEqual(addon.typedArrayBytes(array), Buffer.from([2, 3]));
+assert.deepStrictEqual(addon.dataViewBytes(view), Buffer.from([3, 4]));
+
+// Reading detached ArrayBuffers, or the views of them, is an error.
+structuredClone(buffer, { transfer: [buffer] });
+assert.strictEqual(buffer.byteLength, 0);
+
+const detached = {
+  message:
+    'NapiError: detachable arraybuffer expected (ArrayBuffer is detached)',
+};
+assert.throws(() => addon.arrayBufferBytes(buffer), detached);
+assert.throws(() => addon.bufferBytes(nodeBuffer), detached);
+assert.throws(() => addon.typedArrayBytes(array), detached);
+assert.throws(() => addon.dataViewBytes(view), detached);
+
+// Empty ArrayBuffers are not detached.
+assert.deepStrictEqual(
+  addon.arrayBufferBytes(new ArrayBuffer(0)),
+  Buffer.alloc(0)
+);
+assert.deepStrictEqual(addon.bufferBytes(Buffer.alloc(0)), Buffer.alloc(0));
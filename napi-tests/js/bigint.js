@@ -0,0 +1,78 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+const I64_MAX = 2n ** 63n - 1n;
+const U64_MAX = 2n ** 64n - 1n;
+const I128_MIN = -(2n ** 127n);
+const I128_MAX = 2n ** 127n - 1n;
+const U128_MAX = 2n ** 128n - 1n;
+
+// The conversions tell whether the BigInt fits, and truncate it if not.
+assert.deepStrictEqual(addon.bigintParts(42n), {
+  i64: ['42', true],
+  u64: ['42', true],
+  i128: ['42', true],
+  u128: ['42', true],
+  negative: false,
+  words: ['0x2a'],
+});
+assert.deepStrictEqual(addon.bigintParts(-1n), {
+  i64: ['-1', true],
+  u64: [String(U64_MAX), false],
+  i128: ['-1', true],
+  u128: [String(U128_MAX), false],
+  negative: true,
+  words: ['0x1'],
+});
+assert.deepStrictEqual(addon.bigintParts(I64_MAX + 1n), {
+  i64: [String(-(2n ** 63n)), false],
+  u64: [String(I64_MAX + 1n), true],
+  i128: [String(I64_MAX + 1n), true],
+  u128: [String(I64_MAX + 1n), true],
+  negative: false,
+  words: ['0x8000000000000000'],
+});
+assert.deepStrictEqual(addon.bigintParts(U128_MAX + 2n), {
+  i64: ['1', false],
+  u64: ['1', false],
+  i128: ['1', false],
+  u128: ['1', false],
+  negative: false,
+  words: ['0x1', '0x0', '0x1'],
+});
+assert.deepStrictEqual(addon.bigintParts(0n).words, []);
+assert.throws(() => addon.bigintParts(1), TypeError);
+
+assert.strictEqual(addon.bigintFromWords(false), 0n);
+assert.strictEqual(addon.bigintFromWords(true, 1), -1n);
+assert.strictEqual(
+  addon.bigintFromWords(false, 0, 1, 2),
+  2n ** 32n + 2n ** 64n * 2n
+);
+
+// `i128` and `u128` are BigInts, and take numbers that are integers too.
+assert.strictEqual(addon.checkedAddI128(I128_MAX - 1n, 1n), I128_MAX);
+assert.strictEqual(addon.checkedAddI128(I128_MIN, -1), null);
+assert.strictEqual(addon.checkedAddI128(I128_MIN + 1n, -1), I128_MIN);
+assert.strictEqual(addon.checkedAddI128(2, 3), 5n);
+assert.strictEqual(addon.checkedAddU128(U128_MAX, 0), U128_MAX);
+assert.strictEqual(addon.checkedAddU128(U128_MAX, 1), null);
+
+for (const [add, value, type] of [
+  [addon.checkedAddI128, I128_MAX + 1n, 'i128'],
+  [addon.checkedAddI128, I128_MIN - 1n, 'i128'],
+  [addon.checkedAddU128, U128_MAX + 1n, 'u128'],
+  [addon.checkedAddU128, -1n, 'u128'],
+  [addon.checkedAddU128, -1, 'u128'],
+]) {
+  assert.throws(() => add(value, 0), {
+    name: 'RangeError',
+    message: `argument 1 \`first\`: Value out of range for ${type}`,
+  });
+}
+assert.throws(() => addon.checkedAddI128(1.5, 0), {
+  name: 'RangeError',
+  message: 'argument 1 `first`: Expected an integer for i128',
+});
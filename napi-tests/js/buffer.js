@@ -0,0 +1,45 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// Any ArrayBuffer view is a buffer to Node.js, and is read byte by byte.
+const floats = new Float64Array([1.5, -2]);
+const bytes = Buffer.from(floats.buffer);
+
+assert.deepStrictEqual(addon.bufferBytes(Buffer.from([1, 2, 3])),
+  Buffer.from([1, 2, 3]));
+assert.deepStrictEqual(addon.bufferBytes(floats), bytes);
+assert.deepStrictEqual(addon.bufferBytes(new DataView(floats.buffer, 8)),
+  bytes.subarray(8));
+assert.throws(() => addon.bytesVec(floats), {
+  name: 'TypeError',
+  message: 'argument 1 `bytes`: Buffer, Uint8Array, ArrayBuffer or array ' +
+    'expected',
+});
+
+// Slices are views of the same memory, counted in bytes.
+const slice = addon.bufferSlice(floats, 8, 12);
+assert.deepStrictEqual(Buffer.from(slice), bytes.subarray(8, 12));
+slice[0] = 0xff;
+assert.strictEqual(Buffer.from(floats.buffer)[8], 0xff);
+assert.deepStrictEqual(
+  Buffer.from(addon.bufferSlice(new DataView(floats.buffer, 4), 0, 4)),
+  Buffer.from(floats.buffer, 4, 4)
+);
+assert.throws(() => addon.bufferSlice(Buffer.alloc(4), 2, 5), RangeError);
+
+// And they are buffers themselves, even when the buffer is not.
+const text = Buffer.from('hello world');
+assert(Buffer.isBuffer(addon.bufferSlice(text, 0, 5)));
+assert.strictEqual(addon.bufferSlice(text, 6, 11).toString(), 'world');
+assert(Buffer.isBuffer(addon.bufferSlice(floats, 0, 8)));
+assert(Buffer.isBuffer(addon.bufferSlice(new DataView(floats.buffer), 0, 8)));
+assert(Buffer.isBuffer(addon.bufferSlice(Buffer.alloc(0), 0, 0)));
+
+// Without running the `subarray` method of the buffer, which scripts can
+// replace.
+const patched = Buffer.from([1, 2, 3, 4]);
+patched.subarray = () => ({});
+assert.deepStrictEqual(Buffer.from(addon.bufferSlice(patched, 1, 3)),
+  Buffer.from([2, 3]));
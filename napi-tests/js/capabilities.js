@@ -0,0 +1,15 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// The Node.js versions that run the tests have everything up to N-API 9.
+assert.deepStrictEqual(addon.capabilities(), {
+  dates: true,
+  finalizers: true,
+  bigInts: true,
+  instanceData: true,
+  detach: true,
+  typeTags: true,
+  symbolFor: true,
+});
@@ -0,0 +1,34 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// Functions that only take the context accept any number of arguments.
+let info = addon.callInfo();
+assert.strictEqual(info.argc, 0);
+assert.strictEqual(info.hasData, false);
+assert.strictEqual(info.newTarget, undefined);
+assert.strictEqual(addon.callInfo(1, 'two', {}).argc, 3);
+
+// Methods are called with the object as `this`.
+const object = { callInfo: addon.callInfo };
+assert.strictEqual(object.callInfo().this, object);
+assert.strictEqual(addon.callInfo.call(Math).this, Math);
+
+// Constructors are called with `new.target`, and return the object that the
+// function returns.
+info = new addon.callInfo(1);
+assert.strictEqual(info.argc, 1);
+assert.strictEqual(info.newTarget, addon.callInfo);
+class Derived extends addon.callInfo {}
+assert.strictEqual(new Derived().newTarget, Derived);
+
+// The others check the number of arguments.
+assert.throws(() => addon.maxSize(), {
+  name: 'TypeError',
+  message: 'Expected 1 arguments, but got 0',
+});
+assert.throws(() => addon.maxSize({ limits: { 'max size': 1 } }, 2), {
+  name: 'TypeError',
+  message: 'Expected 1 arguments, but got 2',
+});
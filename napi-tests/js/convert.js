@@ -0,0 +1,77 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// Integers are range-checked both ways.
+assert.deepStrictEqual(addon.integers(-128, 2 ** 32 - 1, 2 ** 53 - 1),
+  [-128, 2 ** 32 - 1, 2 ** 53 - 1]);
+assert.deepStrictEqual(addon.integers(127, 0, -(2n ** 53n) + 1n),
+  [127, 0, -(2 ** 53) + 1]);
+
+for (const [args, message] of [
+  [[-129, 0, 0], 'argument 1 `small`: Value out of range for i8'],
+  [[0, -1, 0], 'argument 2 `unsigned`: Value out of range for u32'],
+  [[0, 2 ** 32, 0], 'argument 2 `unsigned`: Value out of range for u32'],
+  [[0, 0.5, 0], 'argument 2 `unsigned`: Expected an integer for u32'],
+  [[0, 0, Infinity], 'argument 3 `big`: Expected an integer for i64'],
+  // Numbers beyond 2^53 may have been rounded already.
+  [[0, 0, 2 ** 53], 'argument 3 `big`: Value out of range for i64'],
+  [[0, 0, 2n ** 63n], 'argument 3 `big`: Value out of range for i64'],
+]) {
+  assert.throws(() => addon.integers(...args), {name: 'RangeError', message});
+}
+assert.throws(() => addon.integers(0, 0, '1'), {
+  name: 'TypeError',
+  message: 'argument 3 `big`: number expected',
+});
+
+// 64-bit results that a number can't represent exactly are refused.
+assert.strictEqual(addon.wrappingMulU64(2 ** 26, 2 ** 26), 2 ** 52);
+assert.strictEqual(addon.wrappingMulU64(2n ** 32n, 2n ** 32n), 0);
+assert.throws(() => addon.wrappingMulU64(2 ** 27, 2 ** 26), {
+  name: 'RangeError',
+  message: 'Value of type u64 does not fit into a number',
+});
+assert.throws(() => addon.integers(0, 0, -(2n ** 63n)), {
+  name: 'RangeError',
+  message: 'Value of type i64 does not fit into a number',
+});
+
+// Bytes are read from buffers, and arrays of numbers, but not from views of
+// other element types.
+const bytes = Buffer.from([1, 2]);
+assert.deepStrictEqual(addon.bytesVec(bytes), bytes);
+assert.deepStrictEqual(addon.bytesVec(new Uint8Array([1, 2])), bytes);
+assert.deepStrictEqual(addon.bytesVec(new Uint8Array([1, 2]).buffer), bytes);
+assert.deepStrictEqual(addon.bytesVec([1, 2]), bytes);
+assert.throws(() => addon.bytesVec([1, 256]), {
+  name: 'RangeError',
+  message: 'argument 1 `bytes`: Value out of range for u8',
+});
+
+for (const value of [
+  new Uint8ClampedArray(2),
+  new Uint16Array(1),
+  new DataView(new ArrayBuffer(2)),
+  '\x01\x02',
+  {length: 2},
+]) {
+  assert.throws(() => addon.bytesVec(value), {
+    name: 'TypeError',
+    message: 'argument 1 `bytes`: Buffer, Uint8Array, ArrayBuffer or ' +
+      'array expected',
+  });
+}
+
+// Maps hold the own enumerable properties of objects only.
+const object = Object.create({inherited: 1});
+object.b = 2;
+object.a = 3;
+Object.defineProperty(object, 'hidden', {value: 4});
+assert.deepStrictEqual(addon.mapEntries(object), [['a', 3], ['b', 2]]);
+assert.deepStrictEqual(addon.mapEntries({}), []);
+assert.throws(() => addon.mapEntries({a: -1}), {
+  name: 'RangeError',
+  message: 'argument 1 `map`: Value out of range for u32',
+});
@@ -0,0 +1,62 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// The latest and earliest dates JavaScript can represent.
+const MAX = 8.64e15;
+const MIN = -8.64e15;
+
+assert.strictEqual(addon.dateMillis(new Date(1500)), 1500);
+assert.strictEqual(addon.dateMillis(new Date(MIN)), MIN);
+assert.throws(() => addon.dateMillis(1500), {
+  name: 'TypeError',
+  message: 'argument 1 `date`: Date expected',
+});
+
+// Invalid dates fail every conversion.
+for (const convert of [
+  addon.dateMillis,
+  addon.systemTimeMillis,
+  addon.systemTimeRoundTrip,
+  addon.chronoMillis,
+  addon.timeMillis,
+]) {
+  assert.throws(() => convert(new Date(NaN)), {
+    name: 'Error',
+    message: 'NapiError: invalid date',
+  });
+}
+
+for (const millis of [0, 1500, -1500, 1e12, -1e12]) {
+  const date = new Date(millis);
+  assert.strictEqual(addon.systemTimeMillis(date), millis);
+  assert.strictEqual(addon.chronoMillis(date), millis);
+  assert.strictEqual(addon.timeMillis(date), millis);
+
+  const copy = addon.systemTimeRoundTrip(date);
+  assert.ok(copy instanceof Date);
+  assert.notStrictEqual(copy, date);
+  assert.strictEqual(copy.getTime(), millis);
+
+  assert.strictEqual(addon.chronoDate(millis).getTime(), millis);
+  const nanos = BigInt(millis) * 1000000n;
+  assert.strictEqual(addon.timeDate(nanos).getTime(), millis);
+}
+
+assert.strictEqual(addon.systemTimeRoundTrip(new Date(MAX)).getTime(), MAX);
+assert.strictEqual(addon.systemTimeRoundTrip(new Date(MIN)).getTime(), MIN);
+
+// Partial milliseconds are rounded down, also before the epoch.
+assert.strictEqual(addon.timeDate(1500999999).getTime(), 1500);
+assert.strictEqual(addon.timeDate(-1500000001).getTime(), -1501);
+
+// Neither chrono nor time reach as far as JavaScript does.
+assert.throws(() => addon.chronoMillis(new Date(MAX)), {
+  name: 'Error',
+  message: 'NapiError: invalid date (8640000000000000 is out of range)',
+});
+assert.throws(() => addon.timeMillis(new Date(MIN)), {
+  name: 'Error',
+  message: 'NapiError: invalid date (timestamp was not in range)',
+});
@@ -0,0 +1,32 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+const cases = [
+  [undefined, 'Undefined'],
+  [null, 'Null'],
+  [true, 'Boolean'],
+  [1.5, 'Number'],
+  ['', 'String'],
+  [Symbol('symbol'), 'Symbol'],
+  [1n, 'BigInt'],
+  [[], 'Array'],
+  [Buffer.alloc(4), 'Buffer'],
+  [new Uint8Array(4), 'Buffer'],
+  [new Uint8ClampedArray(4), 'TypedArray'],
+  [new Float64Array(4), 'TypedArray'],
+  [new BigInt64Array(4), 'TypedArray'],
+  [new ArrayBuffer(4), 'ArrayBuffer'],
+  [new DataView(new ArrayBuffer(4)), 'DataView'],
+  [new Date(), 'Date'],
+  [Promise.resolve(), 'Promise'],
+  [() => {}, 'Function'],
+  [addon.external(), 'External'],
+  [new TypeError(), 'Error'],
+  [{}, 'Object'],
+];
+
+for (const [value, variant] of cases) {
+  assert.strictEqual(addon.downcast(value), variant, variant);
+}
@@ -0,0 +1,15 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// Unit variants of untagged enums are represented by their names.
+assert.strictEqual(addon.timeout('never'), 'never');
+assert.strictEqual(addon.timeout('default'), 'default');
+assert.strictEqual(addon.timeout(1.5), 1.5);
+assert.throws(() => addon.timeout(null), TypeError);
+assert.throws(() => addon.timeout('always'), TypeError);
+
+// The fields of newtype variants of tagged enums get the tag added.
+const change = { type: 'set', kind: 'limits', 'max size': 3 };
+assert.deepStrictEqual(addon.change(change), change);
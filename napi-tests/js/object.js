@@ -0,0 +1,16 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+assert.strictEqual(addon.maxSize({ limits: { 'max size': 3 } }), 3);
+
+// The names of nested fields are joined with dots, whatever they contain.
+assert.throws(() => addon.maxSize({ limits: { 'max size': 'x' } }), {
+  name: 'TypeError',
+  message: "argument 1 `options`: limits.max size: number expected",
+});
+assert.throws(() => addon.maxSize({}), {
+  name: 'TypeError',
+  message: /^argument 1 `options`: limits: /,
+});
@@ -0,0 +1,63 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// The first overload that the arguments match is picked.
+assert.strictEqual(addon.open('a.txt'), 'path a.txt');
+assert.strictEqual(addon.open('a.txt', { flags: 'r' }), 'path a.txt with r');
+assert.strictEqual(
+  addon.open(Buffer.from([1, 2, 3]), { flags: 'w' }),
+  '3 bytes with w'
+);
+
+// When none does, the error lists why each of them did not.
+assert.throws(() => addon.open(42), {
+  name: 'TypeError',
+  message: [
+    'No overload matches the arguments, expected one of:',
+    '  (path: string)',
+    '    argument 1 `path`: string expected',
+    '  (path: string, options: OpenOptions)',
+    '    Expected 2 arguments, but got 1',
+    '  (buffer: Buffer, options: OpenOptions)',
+    '    Expected 2 arguments, but got 1',
+  ].join('\n'),
+});
+assert.throws(() => addon.open(Buffer.alloc(1), {}), {
+  name: 'TypeError',
+  message: [
+    'No overload matches the arguments, expected one of:',
+    '  (path: string)',
+    '    Expected 1 arguments, but got 2',
+    '  (path: string, options: OpenOptions)',
+    '    argument 1 `path`: string expected',
+    '  (buffer: Buffer, options: OpenOptions)',
+    '    argument 2 `options`: flags: string expected',
+  ].join('\n'),
+});
+
+// Exceptions thrown while trying one are rethrown as they are.
+const error = new Error('no flags');
+assert.throws(
+  () => addon.open('a.txt', {
+    get flags() {
+      throw error;
+    },
+  }),
+  (thrown) => thrown === error
+);
+assert.strictEqual(error.message, 'no flags');
+
+// Even from nested objects.
+assert.throws(
+  () => addon.maxSize({
+    limits: {
+      get 'max size'() {
+        throw error;
+      },
+    },
+  }),
+  (thrown) => thrown === error
+);
+assert.strictEqual(error.message, 'no flags');
@@ -0,0 +1,40 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// The `Ok` values of handlers are returned, and their errors thrown as
+// `Error`s with the messages of the errors.
+assert.strictEqual(addon.parseInt('12'), 12);
+assert.strictEqual(addon.checkedDiv(7, 2), 3);
+assert.strictEqual(addon.checkedByte(7), 7);
+assert.strictEqual(addon.checkedByte(0), null);
+
+for (const [fn, message] of [
+  [() => addon.parseInt('1x'), 'invalid digit found in string'],
+  [() => addon.checkedDiv(7, 0), 'Division by zero'],
+  [() => addon.checkedByte(256), '256 does not fit'],
+  [() => addon.boxedError('boom'), 'boom'],
+]) {
+  assert.throws(fn, (error) => {
+    assert.strictEqual(Object.getPrototypeOf(error), Error.prototype);
+    assert.strictEqual(error.message, message);
+    return true;
+  });
+}
+
+// `NapiError`s keep their class.
+assert.throws(() => addon.rangeError('too far'), {
+  name: 'RangeError',
+  message: 'too far',
+});
+
+// And the exceptions that JavaScript threw are rethrown as they are.
+const error = new SyntaxError('thrown');
+assert.throws(() => addon.callFunction(() => {
+  throw error;
+}), (thrown) => thrown === error);
+assert.throws(() => addon.callFunction(() => {
+  throw 42;
+}), (thrown) => thrown === 42);
+assert.strictEqual(addon.callFunction(() => 'returned'), 'returned');
@@ -0,0 +1,48 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+// New symbols are unique, whatever their description.
+const symbol = addon.newSymbol('tag');
+assert.strictEqual(typeof symbol, 'symbol');
+assert.strictEqual(symbol.description, 'tag');
+assert.notStrictEqual(addon.newSymbol('tag'), symbol);
+assert.strictEqual(addon.newSymbol().description, undefined);
+
+assert.strictEqual(addon.symbolDescription(symbol), 'tag');
+assert.strictEqual(addon.symbolDescription(Symbol()), null);
+assert.strictEqual(addon.symbolDescription(Symbol('')), '');
+assert.throws(() => addon.symbolDescription('tag'), TypeError);
+
+// Symbols for keys are those of the global registry.
+assert.strictEqual(addon.symbolFor('app.tag'), Symbol.for('app.tag'));
+assert.strictEqual(Symbol.keyFor(addon.symbolFor('app.tag')), 'app.tag');
+assert.strictEqual(addon.symbolFor('é'), Symbol.for('é'));
+
+// Well-known symbols are the ones scripts see.
+for (const name of [
+  'iterator',
+  'asyncIterator',
+  'hasInstance',
+  'toPrimitive',
+  'toStringTag',
+  'dispose',
+  'asyncDispose',
+]) {
+  assert.strictEqual(addon.wellKnownSymbol(name), Symbol[name], name);
+}
+
+// Symbols key properties, including the well-known ones.
+const object = { [symbol]: 1, tag: 2 };
+assert.strictEqual(addon.swapBySymbol(object, symbol, 3), 1);
+assert.strictEqual(object[symbol], 3);
+assert.strictEqual(object.tag, 2);
+assert.strictEqual(addon.swapBySymbol(object, Symbol('tag'), 4), undefined);
+
+const iterable = {};
+addon.swapBySymbol(iterable, addon.wellKnownSymbol('iterator'), function* () {
+  yield 1;
+  yield 2;
+});
+assert.deepStrictEqual([...iterable], [1, 2]);
@@ -0,0 +1,18 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+const floats = new Float32Array([1, 2]);
+const bytes = Buffer.from(floats.buffer);
+assert.deepStrictEqual(addon.typedArrayBytes(floats), bytes);
+assert.deepStrictEqual(addon.typedArrayBytes(new BigInt64Array(2)),
+  Buffer.alloc(16));
+
+// The length is not taken from `byteLength`, which scripts can redefine.
+for (const byteLength of [3, 1e6, -1]) {
+  const array = new Uint8Array([1, 2, 3, 4]);
+  Object.defineProperty(array, 'byteLength', { value: byteLength });
+  assert.deepStrictEqual(addon.typedArrayBytes(array),
+    Buffer.from([1, 2, 3, 4]));
+}
@@ -0,0 +1,40 @@
+'use strict';
+
+const assert = require('assert');
+const addon = require(process.env.NAPI_TESTS_ADDON);
+
+assert.strictEqual(addon.validated(0, 'a'), '0 a');
+assert.strictEqual(addon.validated(255, '_x1', ['t']), '255 _x1 t');
+
+// Out of range values and lengths are `RangeError`s.
+assert.throws(() => addon.validated(256, 'a'), {
+  name: 'RangeError',
+  message: 'argument 1 `byte`: must be in range 0..=255',
+});
+assert.throws(() => addon.validated(1, 'abcdefghi'), {
+  name: 'RangeError',
+  message: 'argument 2 `name`: must not be longer than 8',
+});
+
+// The others are `TypeError`s.
+assert.throws(() => addon.validated(1, ''), {
+  name: 'TypeError',
+  message: 'argument 2 `name`: must not be empty',
+});
+assert.throws(() => addon.validated(1, '1a'), {
+  name: 'TypeError',
+  message: 'argument 2 `name`: "1a" is not an identifier',
+});
+assert.throws(() => addon.validated(1, 'a', []), {
+  name: 'TypeError',
+  message: 'argument 3 `tags`: must not be empty',
+});
+
+// Lengths are counted in characters.
+assert.strictEqual(addon.validated(1, 'ééééé'), '1 ééééé');
+
+// Values are converted before they are validated.
+assert.throws(() => addon.validated(-1, 'a'), {
+  name: 'RangeError',
+  message: 'argument 1 `byte`: Value out of range for u32',
+});
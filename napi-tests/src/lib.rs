@@ -0,0 +1,573 @@
+// An addon exercising `napi` from JavaScript, loaded by the tests in `js/`.
+
+extern crate napi;
+#[macro_use]
+extern crate napi_derive;
+
+extern crate chrono;
+extern crate time;
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::num::ParseIntError;
+use std::ptr;
+use std::time::UNIX_EPOCH;
+
+use chrono::{TimeZone, Utc};
+use time::OffsetDateTime;
+
+use napi::{sys, CallContext, IntoNapiError, NapiAny, NapiArgs,
+           NapiArrayBuffer, NapiBigInt, NapiBuffer, NapiDataView, NapiDate,
+           NapiDowncast, NapiError, NapiFunction, NapiObject, NapiResult,
+           NapiString, NapiSymbol, NapiTypedArray};
+
+#[napi]
+fn array_buffer_bytes<'a>(
+    _: &CallContext<'a>,
+    buffer: NapiArrayBuffer<'a>,
+) -> NapiResult<Vec<u8>> {
+    Ok(buffer.bytes()?.to_vec())
+}
+
+#[napi]
+fn buffer_bytes<'a>(
+    _: &CallContext<'a>,
+    buffer: NapiBuffer<'a>,
+) -> NapiResult<Vec<u8>> {
+    Ok(buffer.bytes()?.to_vec())
+}
+
+#[napi]
+fn buffer_slice<'a>(
+    _: &CallContext<'a>,
+    buffer: NapiBuffer<'a>,
+    start: u32,
+    end: u32,
+) -> NapiResult<NapiBuffer<'a>> {
+    buffer.slice(start as usize..end as usize)
+}
+
+#[napi]
+fn bytes_vec(bytes: Vec<u8>) -> Vec<u8> {
+    bytes
+}
+
+#[napi]
+fn downcast<'a>(_: &CallContext<'a>, value: NapiAny<'a>) -> NapiResult<String> {
+    Ok(match value.classify()? {
+        NapiDowncast::Undefined(_) => "Undefined",
+        NapiDowncast::Null(_) => "Null",
+        NapiDowncast::Boolean(_) => "Boolean",
+        NapiDowncast::Number(_) => "Number",
+        NapiDowncast::String(_) => "String",
+        NapiDowncast::Symbol(_) => "Symbol",
+        NapiDowncast::BigInt(_) => "BigInt",
+        NapiDowncast::Array(_) => "Array",
+        NapiDowncast::Buffer(_) => "Buffer",
+        NapiDowncast::TypedArray(_) => "TypedArray",
+        NapiDowncast::ArrayBuffer(_) => "ArrayBuffer",
+        NapiDowncast::DataView(_) => "DataView",
+        NapiDowncast::Date(_) => "Date",
+        NapiDowncast::Promise(_) => "Promise",
+        NapiDowncast::Function(_) => "Function",
+        NapiDowncast::External(_) => "External",
+        NapiDowncast::Error(_) => "Error",
+        NapiDowncast::Object(_) => "Object",
+    }
+    .to_owned())
+}
+
+// Scripts can't create externals themselves.
+#[napi]
+fn external<'a>(context: &CallContext<'a>) -> NapiResult<NapiAny<'a>> {
+    let env = context.env();
+    let mut value = ptr::null_mut();
+
+    env.handle_status(unsafe {
+        sys::napi_create_external(
+            env.as_sys_env(),
+            ptr::null_mut(),
+            None,
+            ptr::null_mut(),
+            &mut value,
+        )
+    })?;
+
+    Ok(NapiAny::with_value(env, value))
+}
+
+#[derive(NapiObject)]
+struct Limits {
+    #[napi(rename = "max size")]
+    max_size: u32,
+}
+
+#[derive(NapiObject)]
+struct Options {
+    limits: Limits,
+}
+
+#[derive(NapiEnum)]
+#[napi(untagged)]
+enum Timeout {
+    Never,
+    #[napi(rename = "default")]
+    Default,
+    Millis(f64),
+}
+
+#[napi]
+fn timeout(value: Timeout) -> Timeout {
+    value
+}
+
+#[derive(NapiEnum)]
+#[napi(tag = "kind")]
+enum Setting {
+    Limits(Limits),
+}
+
+#[derive(NapiEnum)]
+enum Change {
+    Set(Setting),
+}
+
+#[napi]
+fn change(value: Change) -> Change {
+    value
+}
+
+#[napi]
+fn max_size(options: Options) -> u32 {
+    options.limits.max_size
+}
+
+#[napi]
+fn typed_array_bytes<'a>(
+    _: &CallContext<'a>,
+    array: NapiTypedArray<'a>,
+) -> NapiResult<Vec<u8>> {
+    Ok(array.bytes()?.to_vec())
+}
+
+#[napi]
+fn data_view_bytes<'a>(
+    _: &CallContext<'a>,
+    view: NapiDataView<'a>,
+) -> NapiResult<Vec<u8>> {
+    Ok(view.bytes()?.to_vec())
+}
+
+// What each conversion of a BigInt reads, and whether it is lossless.
+#[derive(NapiObject)]
+#[napi(rename_all = "camelCase")]
+struct BigIntParts {
+    i64: (String, bool),
+    u64: (String, bool),
+    i128: (String, bool),
+    u128: (String, bool),
+    negative: bool,
+    words: Vec<String>,
+}
+
+#[napi]
+fn bigint_parts<'a>(
+    _: &CallContext<'a>,
+    value: NapiBigInt<'a>,
+) -> NapiResult<BigIntParts> {
+    fn part<T: ToString>((value, lossless): (T, bool)) -> (String, bool) {
+        (value.to_string(), lossless)
+    }
+
+    let (negative, words) = value.to_words()?;
+
+    Ok(BigIntParts {
+        i64: part(value.to_i64()?),
+        u64: part(value.to_u64()?),
+        i128: part(value.to_i128()?),
+        u128: part(value.to_u128()?),
+        negative,
+        words: words.iter().map(|word| format!("{:#x}", word)).collect(),
+    })
+}
+
+#[napi]
+fn bigint_from_words<'a>(
+    context: &CallContext<'a>,
+    negative: bool,
+    #[napi(rest)] words: Vec<u32>,
+) -> NapiResult<NapiBigInt<'a>> {
+    // Words of 32 bits, since numbers can't hold those of 64.
+    let words = words
+        .chunks(2)
+        .map(|pair| {
+            pair[0] as u64 | pair.get(1).map_or(0, |&high| (high as u64) << 32)
+        })
+        .collect::<Vec<_>>();
+
+    NapiBigInt::from_words(context.env(), negative, &words)
+}
+
+#[napi]
+fn checked_add_i128(first: i128, second: i128) -> Option<i128> {
+    first.checked_add(second)
+}
+
+#[napi]
+fn checked_add_u128(first: u128, second: u128) -> Option<u128> {
+    first.checked_add(second)
+}
+
+#[napi]
+fn date_millis<'a>(_: &CallContext<'a>, date: NapiDate<'a>) -> NapiResult<f64> {
+    date.to_millis()
+}
+
+// Milliseconds since the epoch, counted by `SystemTime` itself.
+#[napi]
+fn system_time_millis<'a>(
+    _: &CallContext<'a>,
+    date: NapiDate<'a>,
+) -> NapiResult<f64> {
+    let time = date.to_system_time()?;
+
+    Ok(match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_millis() as f64,
+        Err(error) => -(error.duration().as_millis() as f64),
+    })
+}
+
+#[napi]
+fn system_time_round_trip<'a>(
+    context: &CallContext<'a>,
+    date: NapiDate<'a>,
+) -> NapiResult<NapiDate<'a>> {
+    NapiDate::from_system_time(context.env(), date.to_system_time()?)
+}
+
+#[napi]
+fn chrono_millis<'a>(
+    _: &CallContext<'a>,
+    date: NapiDate<'a>,
+) -> NapiResult<i64> {
+    Ok(date.to_chrono()?.timestamp_millis())
+}
+
+#[napi]
+fn chrono_date<'a>(
+    context: &CallContext<'a>,
+    millis: i64,
+) -> NapiResult<Option<NapiDate<'a>>> {
+    match Utc.timestamp_millis_opt(millis).single() {
+        Some(time) => NapiDate::from_chrono(context.env(), &time).map(Some),
+        None => Ok(None),
+    }
+}
+
+#[napi]
+fn time_millis<'a>(
+    _: &CallContext<'a>,
+    date: NapiDate<'a>,
+) -> NapiResult<i64> {
+    Ok((date.to_time()?.unix_timestamp_nanos() / 1_000_000) as i64)
+}
+
+// Takes nanoseconds, to check that partial milliseconds are rounded down.
+#[napi]
+fn time_date<'a>(
+    context: &CallContext<'a>,
+    nanos: i64,
+) -> NapiResult<Option<NapiDate<'a>>> {
+    match OffsetDateTime::from_unix_timestamp_nanos(i128::from(nanos)) {
+        Ok(time) => NapiDate::from_time(context.env(), time).map(Some),
+        Err(_) => Ok(None),
+    }
+}
+
+#[napi]
+fn integers(small: i8, unsigned: u32, big: i64) -> (i8, u32, i64) {
+    (small, unsigned, big)
+}
+
+#[napi]
+fn wrapping_mul_u64(first: u64, second: u64) -> u64 {
+    first.wrapping_mul(second)
+}
+
+#[napi]
+fn map_entries(map: BTreeMap<String, u32>) -> Vec<(String, u32)> {
+    map.into_iter().collect()
+}
+
+#[napi]
+fn parse_int(text: String) -> Result<i32, ParseIntError> {
+    text.parse()
+}
+
+#[napi]
+fn checked_div(first: i32, second: i32) -> Result<i32, String> {
+    first.checked_div(second).ok_or_else(|| "Division by zero".to_string())
+}
+
+#[derive(Debug)]
+struct Overflow(u32);
+
+impl Display for Overflow {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{} does not fit", self.0)
+    }
+}
+
+impl IntoNapiError for Overflow {}
+
+#[napi]
+fn checked_byte(value: u32) -> Result<Option<u8>, Overflow> {
+    match value {
+        0 => Ok(None),
+        1..=255 => Ok(Some(value as u8)),
+        _ => Err(Overflow(value)),
+    }
+}
+
+#[napi]
+fn boxed_error(message: String) -> Result<(), Box<dyn Error>> {
+    Err(message.into())
+}
+
+#[napi]
+fn range_error<'a>(
+    context: &CallContext<'a>,
+    message: String,
+) -> NapiResult<()> {
+    let env = context.env();
+    Err(NapiError::range_error(env, &NapiString::from_str(env, &message)?))
+}
+
+#[napi]
+fn call_function<'a>(
+    context: &CallContext<'a>,
+    function: NapiFunction<'a>,
+) -> NapiResult<NapiAny<'a>> {
+    function.call(&context.this_any(), &[])
+}
+
+#[napi]
+fn new_symbol<'a>(
+    context: &CallContext<'a>,
+    description: Option<String>,
+) -> NapiResult<NapiSymbol<'a>> {
+    NapiSymbol::new(context.env(), description.as_deref())
+}
+
+#[napi]
+fn symbol_for<'a>(
+    context: &CallContext<'a>,
+    key: String,
+) -> NapiResult<NapiSymbol<'a>> {
+    NapiSymbol::for_key(context.env(), &key)
+}
+
+#[napi]
+fn well_known_symbol<'a>(
+    context: &CallContext<'a>,
+    name: String,
+) -> NapiResult<NapiSymbol<'a>> {
+    let env = context.env();
+
+    match name.as_str() {
+        "iterator" => NapiSymbol::iterator(env),
+        "asyncIterator" => NapiSymbol::async_iterator(env),
+        "hasInstance" => NapiSymbol::has_instance(env),
+        "toPrimitive" => NapiSymbol::to_primitive(env),
+        "toStringTag" => NapiSymbol::to_string_tag(env),
+        "dispose" => NapiSymbol::dispose(env),
+        "asyncDispose" => NapiSymbol::async_dispose(env),
+        _ => unreachable!(),
+    }
+}
+
+#[napi]
+fn symbol_description<'a>(
+    _: &CallContext<'a>,
+    symbol: NapiSymbol<'a>,
+) -> NapiResult<Option<String>> {
+    symbol.description()
+}
+
+// Swaps the value under the symbol for `value`, and returns the previous one.
+#[napi]
+fn swap_by_symbol<'a>(
+    _: &CallContext<'a>,
+    object: NapiObject<'a>,
+    key: NapiSymbol<'a>,
+    value: NapiAny<'a>,
+) -> NapiResult<NapiAny<'a>> {
+    let previous = object.get_property(&key)?;
+    object.set_property(&key, &value)?;
+    Ok(previous)
+}
+
+#[napi]
+fn optional(value: Option<f64>) -> String {
+    match value {
+        Some(value) => format!("some {}", value),
+        None => "none".to_owned(),
+    }
+}
+
+#[napi]
+fn repeat(
+    text: String,
+    #[napi(default = 2)] times: u32,
+    #[napi(default)] separator: String,
+) -> String {
+    vec![text; times as usize].join(&separator)
+}
+
+#[napi]
+fn sum_from(start: f64, #[napi(rest)] numbers: Vec<f64>) -> f64 {
+    numbers.iter().fold(start, |sum, number| sum + number)
+}
+
+fn is_identifier(name: &String) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid = chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("{:?} is not an identifier", name))
+    }
+}
+
+#[napi]
+fn validated(
+    #[napi(range = 0..=255)] byte: u32,
+    #[napi(non_empty, max_len = 8, validate = "is_identifier")] name: String,
+    #[napi(non_empty)] tags: Option<Vec<String>>,
+) -> String {
+    match tags {
+        Some(tags) => format!("{} {} {}", byte, name, tags.join(",")),
+        None => format!("{} {}", byte, name),
+    }
+}
+
+#[derive(NapiObject)]
+struct OpenOptions {
+    flags: String,
+}
+
+#[derive(NapiArgs)]
+enum OpenArgs {
+    Path { path: String },
+    PathWithOptions { path: String, options: OpenOptions },
+    Buffer { buffer: Vec<u8>, options: OpenOptions },
+}
+
+#[napi]
+fn open<'a>(context: &CallContext<'a>) -> NapiResult<String> {
+    Ok(match OpenArgs::from_context(context)? {
+        OpenArgs::Path { path } => format!("path {}", path),
+        OpenArgs::PathWithOptions { path, options } => {
+            format!("path {} with {}", path, options.flags)
+        }
+        OpenArgs::Buffer { buffer, options } => {
+            format!("{} bytes with {}", buffer.len(), options.flags)
+        }
+    })
+}
+
+#[derive(NapiObject)]
+#[napi(rename_all = "camelCase")]
+struct CallInfo<'a> {
+    argc: u32,
+    has_data: bool,
+    this: NapiAny<'a>,
+    new_target: Option<NapiFunction<'a>>,
+}
+
+// Functions that only take the context accept any number of arguments.
+#[napi]
+fn call_info<'a>(context: &CallContext<'a>) -> NapiResult<CallInfo<'a>> {
+    Ok(CallInfo {
+        argc: context.argc() as u32,
+        has_data: !context.data().is_null(),
+        this: context.this_any(),
+        new_target: context.new_target()?,
+    })
+}
+
+#[derive(NapiObject)]
+#[napi(rename_all = "camelCase")]
+struct Capabilities {
+    dates: bool,
+    finalizers: bool,
+    big_ints: bool,
+    instance_data: bool,
+    detach: bool,
+    type_tags: bool,
+    symbol_for: bool,
+}
+
+#[napi]
+fn capabilities<'a>(context: &CallContext<'a>) -> NapiResult<Capabilities> {
+    let capabilities = context.env().capabilities()?;
+
+    Ok(Capabilities {
+        dates: capabilities.dates(),
+        finalizers: capabilities.finalizers(),
+        big_ints: capabilities.big_ints(),
+        instance_data: capabilities.instance_data(),
+        detach: capabilities.detach(),
+        type_tags: capabilities.type_tags(),
+        symbol_for: capabilities.symbol_for(),
+    })
+}
+
+napi_module!(
+    array_buffer_bytes,
+    bigint_from_words,
+    bigint_parts,
+    boxed_error,
+    buffer_bytes,
+    buffer_slice,
+    bytes_vec,
+    call_function,
+    call_info,
+    capabilities,
+    change,
+    checked_byte,
+    checked_div,
+    checked_add_i128,
+    checked_add_u128,
+    chrono_date,
+    chrono_millis,
+    date_millis,
+    downcast,
+    external,
+    integers,
+    map_entries,
+    max_size,
+    new_symbol,
+    open,
+    optional,
+    parse_int,
+    range_error,
+    repeat,
+    sum_from,
+    swap_by_symbol,
+    symbol_description,
+    symbol_for,
+    system_time_millis,
+    system_time_round_trip,
+    time_date,
+    time_millis,
+    timeout,
+    typed_array_bytes,
+    validated,
+    well_known_symbol,
+    wrapping_mul_u64,
+    data_view_bytes,
+);
@@ -0,0 +1,48 @@
+// Runs every script in `js/` with Node.js, passing it the path of the addon
+// built from this crate in `NAPI_TESTS_ADDON`.  The tests are skipped when
+// Node.js is not installed.
+
+use std::env;
+use std::env::consts::{DLL_PREFIX, DLL_SUFFIX};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+#[test]
+fn node() {
+    let node = env::var_os("NODE").unwrap_or_else(|| "node".into());
+    if Command::new(&node).arg("--version").output().is_err() {
+        eprintln!("Node.js not found, skipping the tests");
+        return;
+    }
+
+    // The addon is built next to the test binary, and Node.js only loads it
+    // with a `.node` suffix.
+    let exe = env::current_exe().unwrap();
+    let dir = exe.parent().unwrap();
+    let library = dir.join(format!("{}napi_tests{}", DLL_PREFIX, DLL_SUFFIX));
+    let addon = dir.join("napi_tests.node");
+    fs::copy(&library, &addon).unwrap();
+
+    let scripts = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("js");
+    let mut scripts = fs::read_dir(scripts)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "js"))
+        .collect::<Vec<_>>();
+    scripts.sort();
+
+    let mut failed = Vec::new();
+    for script in &scripts {
+        let status = Command::new(&node)
+            .arg(script)
+            .env("NAPI_TESTS_ADDON", &addon)
+            .status()
+            .unwrap();
+        if !status.success() {
+            failed.push(script.display().to_string());
+        }
+    }
+
+    assert!(failed.is_empty(), "failed: {}", failed.join(", "));
+}
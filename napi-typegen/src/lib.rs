@@ -0,0 +1,228 @@
+// Generates the TypeScript declarations (`index.d.ts`) of the functions,
+// objects and enums that an addon exports using `napi-derive`.
+//
+// The macros of `napi-derive` write the declaration of every item they
+// expand into the directory named by `NAPI_TYPE_DEF_DIR`, and the crate is
+// rebuilt whenever that variable changes, so the declarations are collected
+// by building the crate with it pointing to a fresh directory.
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const TYPE_DEF_DIR: &str = "NAPI_TYPE_DEF_DIR";
+
+const HEADER: &str = "/* Generated by napi-typegen, do not edit. */\n";
+
+// A temporary directory that the declarations are written to during a
+// build, removed when dropped.
+#[derive(Debug)]
+pub struct TypeDefDir {
+    path: PathBuf,
+}
+
+impl TypeDefDir {
+    pub fn new() -> io::Result<Self> {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.subsec_nanos())
+            .unwrap_or(0);
+        let path = env::temp_dir().join(format!(
+            "napi-typegen-{}-{}",
+            process::id(),
+            nanos,
+        ));
+
+        fs::create_dir(&path)?;
+
+        Ok(TypeDefDir { path })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    // Makes the build run by `command` write the declarations here.
+    pub fn apply(&self, command: &mut Command) {
+        command.env(TYPE_DEF_DIR, &self.path);
+    }
+
+    pub fn render(&self) -> io::Result<String> {
+        render(&self.path)
+    }
+}
+
+impl Drop for TypeDefDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+// A declaration written by `napi-derive`, stored in a file named
+// `<crate>.<kind>.<name>.<hash>.d.ts`.
+struct Fragment {
+    kind: String,
+    name: String,
+    contents: String,
+}
+
+// Renders the declarations in `dir` as the contents of `index.d.ts`: the
+// types first and then the functions, each sorted by name.  When the
+// functions were registered with `napi_module!`, only those are declared.
+// Fails if different items of the same kind and name are declared, e.g. by
+// functions of the same name in different modules.
+pub fn render(dir: &Path) -> io::Result<String> {
+    let mut fragments = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let file_name = match path.file_name().and_then(|name| name.to_str())
+        {
+            Some(name) => name.to_owned(),
+            None => continue,
+        };
+        let stem = match file_name.strip_suffix(".d.ts") {
+            Some(stem) => stem,
+            None => continue,
+        };
+
+        let mut parts = stem.splitn(4, '.').skip(1);
+        if let (Some(kind), Some(name), Some(_)) =
+            (parts.next(), parts.next(), parts.next())
+        {
+            fragments.push(Fragment {
+                kind: kind.to_owned(),
+                name: name.to_owned(),
+                contents: fs::read_to_string(&path)?,
+            });
+        }
+    }
+
+    let modules = fragments
+        .iter()
+        .filter(|fragment| fragment.kind == "module")
+        .collect::<Vec<_>>();
+    let exports = modules
+        .iter()
+        .flat_map(|fragment| fragment.contents.lines())
+        .collect::<HashSet<_>>();
+
+    let mut types = fragments
+        .iter()
+        .filter(|fragment| fragment.kind == "type")
+        .collect::<Vec<_>>();
+    let mut functions = fragments
+        .iter()
+        .filter(|fragment| fragment.kind == "fn")
+        .filter(|fragment| {
+            modules.is_empty() || exports.contains(fragment.name.as_str())
+        })
+        .collect::<Vec<_>>();
+
+    types.sort_by(|a, b| a.name.cmp(&b.name));
+    functions.sort_by(|a, b| a.name.cmp(&b.name));
+
+    for fragments in [&types, &functions] {
+        let duplicate = fragments
+            .windows(2)
+            .find(|pair| pair[0].name == pair[1].name);
+
+        if let Some(pair) = duplicate {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "conflicting declarations of `{}`, the exported {} \
+                     must have unique names",
+                    pair[0].name,
+                    if pair[0].kind == "fn" { "functions" } else { "types" },
+                ),
+            ));
+        }
+    }
+
+    let declarations = types
+        .iter()
+        .chain(&functions)
+        .map(|fragment| fragment.contents.as_str())
+        .collect::<Vec<_>>();
+
+    Ok(format!("{}\n{}", HEADER, declarations.join("\n")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_files(files: &[(&str, &str)]) -> io::Result<String> {
+        let dir = TypeDefDir::new()?;
+        for &(name, contents) in files {
+            fs::write(dir.path().join(name), contents)?;
+        }
+        dir.render()
+    }
+
+    #[test]
+    fn renders_types_before_functions() {
+        let rendered = render_files(&[
+            ("addon.fn.open.0.d.ts", "export function open(): void\n"),
+            ("addon.type.Mode.0.d.ts", "export type Mode = \"r\"\n"),
+            ("addon.fn.close.0.d.ts", "export function close(): void\n"),
+            ("addon.type.Flags.0.d.ts", "export type Flags = number\n"),
+            ("notes.txt", "not a declaration"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            rendered,
+            format!(
+                "{}\n{}\n{}\n{}\n{}",
+                HEADER,
+                "export type Flags = number\n",
+                "export type Mode = \"r\"\n",
+                "export function close(): void\n",
+                "export function open(): void\n",
+            )
+        );
+    }
+
+    #[test]
+    fn declares_only_exported_functions() {
+        let rendered = render_files(&[
+            ("addon.module.exports.0.d.ts", "open\n"),
+            ("addon.fn.open.0.d.ts", "export function open(): void\n"),
+            ("addon.fn.close.0.d.ts", "export function close(): void\n"),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            rendered,
+            format!("{}\nexport function open(): void\n", HEADER)
+        );
+    }
+
+    #[test]
+    fn rejects_conflicting_declarations() {
+        let error = render_files(&[
+            ("addon.type.Options.1.d.ts", "export interface Options {}\n"),
+            ("addon.type.Options.2.d.ts", "export type Options = string\n"),
+        ])
+        .unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("`Options`"));
+
+        let error = render_files(&[
+            ("addon.module.exports.0.d.ts", "open\nclose\n"),
+            ("addon.fn.open.1.d.ts", "export function open(): void\n"),
+            ("addon.fn.open.2.d.ts", "export function open(a: A): void\n"),
+            ("addon.fn.close.0.d.ts", "export function close(): void\n"),
+        ])
+        .unwrap_err();
+
+        assert!(error.to_string().contains("`open`"));
+    }
+}
@@ -0,0 +1,62 @@
+extern crate napi_typegen;
+
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Write};
+use std::process::{self, Command};
+
+use napi_typegen::TypeDefDir;
+
+const USAGE: &str = "\
+Usage: napi-typegen [-o <file>] [<cargo build options>...]
+
+Builds the addon in the current directory and prints the TypeScript
+declarations of its exports, or writes them to <file>.";
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+fn run() -> io::Result<()> {
+    let mut output = None;
+    let mut cargo_args = Vec::new();
+
+    let mut args = env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "-o" || arg == "--output" {
+            output = args.next();
+        } else if arg == "-h" || arg == "--help" {
+            println!("{}", USAGE);
+            return Ok(());
+        } else {
+            cargo_args.push(arg);
+        }
+    }
+
+    let dir = TypeDefDir::new()?;
+    let cargo =
+        env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+
+    let mut command = Command::new(cargo);
+    command.arg("build").args(&cargo_args);
+    dir.apply(&mut command);
+
+    let status = command.status()?;
+    if !status.success() {
+        return Err(io::Error::other(format!(
+            "cargo build failed ({})",
+            status,
+        )));
+    }
+
+    let declarations = dir.render()?;
+
+    match output {
+        Some(path) => fs::write(path, declarations),
+        None => io::stdout().write_all(declarations.as_bytes()),
+    }
+}
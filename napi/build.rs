@@ -0,0 +1,18 @@
+// Tells the build scripts of addons which N-API version the enabled `napiN`
+// features require, as `DEP_NAPI_NAPI_VERSION`, for `napi-build` to check it
+// against Node.js.
+
+use std::env;
+
+// The highest N-API version that a `napiN` feature requests.
+const MAX_NAPI_VERSION: u32 = 9;
+
+fn main() {
+    let version = (1..=MAX_NAPI_VERSION).rev().find(|version| {
+        env::var_os(format!("CARGO_FEATURE_NAPI{}", version)).is_some()
+    });
+
+    if let Some(version) = version {
+        println!("cargo:napi_version={}", version);
+    }
+}
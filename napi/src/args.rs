@@ -1,10 +1,15 @@
+use context::CallContext;
 use env::NapiEnv;
 use result::NapiResult;
 use sys;
 
 pub trait NapiArgs<'env>: Sized {
+    fn from_context(context: &CallContext<'env>) -> NapiResult<Self>;
+
     fn from_cb_info(
         env: &'env NapiEnv,
         cb_info: sys::napi_callback_info,
-    ) -> NapiResult<Self>;
+    ) -> NapiResult<Self> {
+        Self::from_context(&CallContext::new(env, cb_info)?)
+    }
 }
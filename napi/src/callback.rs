@@ -0,0 +1,38 @@
+use std::ptr;
+
+use context::CallContext;
+use env::NapiEnv;
+use result::NapiResult;
+use sys;
+
+pub type NapiCallback =
+    extern "C" fn(sys::napi_env, sys::napi_callback_info) -> sys::napi_value;
+
+// Runs `handler` for a callback invoked by N-API, turning an error into a
+// thrown JavaScript exception and an `undefined` return value.  This is the
+// common part of the functions generated by `napi_callback!` and `#[napi]`.
+#[doc(hidden)]
+pub fn run_callback<F>(
+    env: sys::napi_env,
+    cb_info: sys::napi_callback_info,
+    handler: F,
+) -> sys::napi_value
+where
+    F: for<'env> FnOnce(&CallContext<'env>) -> NapiResult<sys::napi_value>,
+{
+    let env = NapiEnv::from(env);
+
+    match CallContext::new(&env, cb_info).and_then(|context| handler(&context))
+    {
+        Ok(value) => value,
+        Err(error) => {
+            env.throw(error);
+
+            let mut result = ptr::null_mut();
+            unsafe {
+                sys::napi_get_undefined(env.as_sys_env(), &mut result);
+            }
+            result
+        }
+    }
+}
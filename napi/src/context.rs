@@ -0,0 +1,132 @@
+use std::ops::Deref;
+use std::os::raw::c_void;
+use std::ptr;
+
+use convert::FromNapi;
+use env::NapiEnv;
+use result::NapiResult;
+use sys;
+use value::{NapiAny, NapiFunction, NapiValue};
+
+// Everything N-API tells about a single invocation of a callback.  It
+// dereferences to the environment, so handlers that only need a `&NapiEnv`
+// can keep taking one.
+#[derive(Debug)]
+pub struct CallContext<'env> {
+    env: &'env NapiEnv,
+    cb_info: sys::napi_callback_info,
+    this: sys::napi_value,
+    data: *mut c_void,
+    argv: Vec<sys::napi_value>,
+}
+
+impl<'env> CallContext<'env> {
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn new(
+        env: &'env NapiEnv,
+        cb_info: sys::napi_callback_info,
+    ) -> NapiResult<Self> {
+        let mut argc = 0;
+
+        env.handle_status(unsafe {
+            sys::napi_get_cb_info(
+                env.as_sys_env(),
+                cb_info,
+                &mut argc,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            )
+        })?;
+
+        let mut argv = vec![ptr::null_mut(); argc];
+        let mut this = ptr::null_mut();
+        let mut data = ptr::null_mut();
+
+        env.handle_status(unsafe {
+            sys::napi_get_cb_info(
+                env.as_sys_env(),
+                cb_info,
+                &mut argc,
+                argv.as_mut_ptr(),
+                &mut this,
+                &mut data,
+            )
+        })?;
+
+        argv.truncate(argc);
+
+        Ok(Self {
+            env,
+            cb_info,
+            this,
+            data,
+            argv,
+        })
+    }
+
+    pub fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    pub fn argc(&self) -> usize {
+        self.argv.len()
+    }
+
+    // Returns `undefined` for the arguments that were not passed.
+    pub fn arg(&self, idx: usize) -> NapiResult<NapiAny<'env>> {
+        match self.argv.get(idx) {
+            Some(&value) => Ok(NapiAny::with_value(self.env, value)),
+            None => NapiAny::new(self.env),
+        }
+    }
+
+    pub fn this_any(&self) -> NapiAny<'env> {
+        NapiAny::with_value(self.env, self.this)
+    }
+
+    pub fn this<T>(&self) -> NapiResult<T>
+    where
+        T: FromNapi<'env>,
+    {
+        T::from_napi(self.this_any())
+            .map_err(|error| error.context(self.env, "`this`"))
+    }
+
+    // Returns the constructor the function was invoked with by `new`, or
+    // `None` for a regular call.
+    pub fn new_target(&self) -> NapiResult<Option<NapiFunction<'env>>> {
+        let mut value = ptr::null_mut();
+
+        self.env.handle_status(unsafe {
+            sys::napi_get_new_target(
+                self.env.as_sys_env(),
+                self.cb_info,
+                &mut value,
+            )
+        })?;
+
+        if value.is_null() {
+            Ok(None)
+        } else {
+            NapiFunction::from_sys_checked(self.env, value).map(Some)
+        }
+    }
+
+    pub fn is_construct_call(&self) -> NapiResult<bool> {
+        self.new_target().map(|target| target.is_some())
+    }
+
+    // The pointer that was passed as `data` when the function was created.
+    pub fn data(&self) -> *mut c_void {
+        self.data
+    }
+}
+
+impl<'env> Deref for CallContext<'env> {
+    type Target = NapiEnv;
+
+    fn deref(&self) -> &NapiEnv {
+        self.env
+    }
+}
@@ -0,0 +1,650 @@
+use std::char::ParseCharError;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::hash::BuildHasher;
+use std::io;
+use std::num::{ParseFloatError, ParseIntError, TryFromIntError};
+use std::str::{ParseBoolError, Utf8Error};
+use std::string::FromUtf8Error;
+
+use env::NapiEnv;
+use result::{NapiError, NapiResult};
+#[cfg(feature = "napi6")]
+use value::NapiBigInt;
+use value::{NapiAny, NapiArray, NapiBoolean, NapiBuffer, NapiDowncast,
+            NapiNull, NapiNumber, NapiObject, NapiString, NapiUndefined,
+            NapiValue, NapiValueType};
+
+pub trait FromNapi<'env>: Sized {
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self>;
+
+    // Lets `Vec<u8>` accept buffers in addition to arrays.
+    #[doc(hidden)]
+    fn vec_from_napi(value: NapiAny<'env>) -> NapiResult<Vec<Self>> {
+        let array = value.as_array()?;
+        let len = array.len()?;
+
+        (0..len).map(|index| Self::from_napi(array.get(index)?)).collect()
+    }
+}
+
+pub trait ToNapi<'env> {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>>;
+
+    // Lets `Vec<u8>` and `&[u8]` be converted to buffers instead of arrays.
+    #[doc(hidden)]
+    fn slice_to_napi(
+        slice: &[Self],
+        env: &'env NapiEnv,
+    ) -> NapiResult<NapiAny<'env>>
+    where
+        Self: Sized,
+    {
+        let array = NapiArray::with_len(env, slice.len())?;
+
+        for (index, item) in slice.iter().enumerate() {
+            array.set(index as u32, &item.to_napi(env)?)?;
+        }
+
+        Ok(array.as_napi_any())
+    }
+}
+
+// Types that convert to new plain JavaScript objects, which tagged
+// `NapiEnum`s require of the fields of their newtype variants, since the tag
+// is added to the object.  `NapiObject` is not one of them, as adding the tag
+// would change the object of the caller.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not convert to a new JavaScript object",
+    note = "the field of a newtype variant of a tagged `NapiEnum` must be a \
+            type deriving `NapiObject`, another tagged `NapiEnum` or a map"
+)]
+pub trait NapiObjectValue {}
+
+impl<T, S> NapiObjectValue for HashMap<String, T, S> {}
+
+impl<T> NapiObjectValue for BTreeMap<String, T> {}
+
+// Values that can be returned from handlers passed to `napi_callback!`.
+pub trait NapiReturn<'env> {
+    fn into_napi(self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>>;
+}
+
+impl<'env, T> NapiReturn<'env> for T
+where
+    T: ToNapi<'env>,
+{
+    fn into_napi(self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        self.to_napi(env)
+    }
+}
+
+impl<'env, T, E> NapiReturn<'env> for Result<T, E>
+where
+    T: NapiReturn<'env>,
+    E: IntoNapiError,
+{
+    fn into_napi(self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        match self {
+            Ok(value) => value.into_napi(env),
+            Err(error) => Err(error.into_napi_error(env)),
+        }
+    }
+}
+
+// Errors that can be returned from handlers, which throw them.  By default
+// an `Error` with the message of the error is thrown, so that implementing
+// the trait for an error type takes an empty `impl` block.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be thrown as a JavaScript exception",
+    note = "implement `IntoNapiError` for it to throw an `Error` with its \
+            message"
+)]
+pub trait IntoNapiError: Display + Sized {
+    fn into_napi_error(self, env: &NapiEnv) -> NapiError {
+        match NapiString::from_str(env, &self.to_string()) {
+            Ok(message) => NapiError::error(env, &message),
+            Err(error) => error,
+        }
+    }
+}
+
+// `NapiError`s are passed through as is, so that the JavaScript exceptions
+// attached to them are preserved.
+impl IntoNapiError for NapiError {
+    fn into_napi_error(self, _: &NapiEnv) -> NapiError {
+        self
+    }
+}
+
+impl IntoNapiError for String {}
+
+impl IntoNapiError for &str {}
+
+impl IntoNapiError for Box<dyn Error> {}
+
+impl IntoNapiError for Box<dyn Error + Send + Sync> {}
+
+impl IntoNapiError for io::Error {}
+
+impl IntoNapiError for fmt::Error {}
+
+impl IntoNapiError for ParseBoolError {}
+
+impl IntoNapiError for ParseCharError {}
+
+impl IntoNapiError for ParseFloatError {}
+
+impl IntoNapiError for ParseIntError {}
+
+impl IntoNapiError for TryFromIntError {}
+
+impl IntoNapiError for Utf8Error {}
+
+impl IntoNapiError for FromUtf8Error {}
+
+impl<'env, T> FromNapi<'env> for T
+where
+    T: NapiValue<'env>,
+{
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        T::from_sys_checked(value.env(), value.as_sys_value())
+    }
+}
+
+impl<'env, T> ToNapi<'env> for T
+where
+    T: NapiValue<'env>,
+{
+    fn to_napi(&self, _: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        Ok(self.as_napi_any())
+    }
+}
+
+fn range_error<T>(env: &NapiEnv, message: &str) -> NapiResult<T> {
+    let message = NapiString::from_str(env, message)?;
+    Err(NapiError::range_error(env, &message))
+}
+
+impl<'env> FromNapi<'env> for () {
+    fn from_napi(_: NapiAny<'env>) -> NapiResult<Self> {
+        Ok(())
+    }
+}
+
+impl<'env> ToNapi<'env> for () {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        NapiUndefined::new(env).map(|value| value.as_napi_any())
+    }
+}
+
+impl<'env> FromNapi<'env> for bool {
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        value.as_boolean()?.to_bool()
+    }
+}
+
+impl<'env> ToNapi<'env> for bool {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        let value = if *self {
+            NapiBoolean::truth(env)?
+        } else {
+            NapiBoolean::lie(env)?
+        };
+
+        Ok(value.as_napi_any())
+    }
+}
+
+// The largest integer that a JavaScript number can represent exactly.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+// Integers that always fit into a JavaScript number.
+macro_rules! small_int_conversions {
+    ($($ty:ident),*) => {
+        $(
+            impl<'env> FromNapi<'env> for $ty {
+                fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+                    let number = value.as_number()?.to_f64()?;
+                    let number =
+                        number_to_int(value.env(), number, stringify!($ty))?;
+
+                    if number < f64::from($ty::MIN)
+                        || number > f64::from($ty::MAX)
+                    {
+                        return range_error(
+                            value.env(),
+                            concat!("Value out of range for ",
+                                    stringify!($ty)),
+                        );
+                    }
+
+                    Ok(number as $ty)
+                }
+            }
+
+            impl<'env> ToNapi<'env> for $ty {
+                fn to_napi(
+                    &self,
+                    env: &'env NapiEnv,
+                ) -> NapiResult<NapiAny<'env>> {
+                    NapiNumber::from_f64(env, f64::from(*self))
+                        .map(|value| value.as_napi_any())
+                }
+            }
+        )*
+    };
+}
+
+// Integers that may not fit into a JavaScript number, and thus are also
+// accepted as BigInts since N-API 6.
+macro_rules! big_int_conversions {
+    ($($ty:ident),*) => {
+        $(
+            impl<'env> FromNapi<'env> for $ty {
+                fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+                    let env = value.env();
+
+                    #[cfg(feature = "napi6")]
+                    {
+                        if value.value_type()? == NapiValueType::BigInt {
+                            let (number, lossless) =
+                                value.as_bigint()?.to_i128()?;
+                            if !lossless
+                                || number < $ty::MIN as i128
+                                || number > $ty::MAX as i128
+                            {
+                                return range_error(
+                                    env,
+                                    concat!("Value out of range for ",
+                                            stringify!($ty)),
+                                );
+                            }
+                            return Ok(number as $ty);
+                        }
+                    }
+
+                    let number = value.as_number()?.to_f64()?;
+                    let number = number_to_int(env, number, stringify!($ty))?;
+
+                    if number < $ty::MIN as f64 || number > $ty::MAX as f64 {
+                        return range_error(
+                            env,
+                            concat!("Value out of range for ",
+                                    stringify!($ty)),
+                        );
+                    }
+
+                    Ok(number as $ty)
+                }
+            }
+
+            impl<'env> ToNapi<'env> for $ty {
+                fn to_napi(
+                    &self,
+                    env: &'env NapiEnv,
+                ) -> NapiResult<NapiAny<'env>> {
+                    let number = *self as f64;
+                    if number.abs() > MAX_SAFE_INTEGER {
+                        return range_error(
+                            env,
+                            concat!("Value of type ", stringify!($ty),
+                                    " does not fit into a number"),
+                        );
+                    }
+
+                    NapiNumber::from_f64(env, number)
+                        .map(|value| value.as_napi_any())
+                }
+            }
+        )*
+    };
+}
+
+fn number_to_int(env: &NapiEnv, number: f64, ty: &str) -> NapiResult<f64> {
+    if number.fract() != 0.0 || !number.is_finite() {
+        return range_error(env, &format!("Expected an integer for {}", ty));
+    }
+
+    if number.abs() > MAX_SAFE_INTEGER {
+        return range_error(env, &format!("Value out of range for {}", ty));
+    }
+
+    Ok(number)
+}
+
+small_int_conversions!(i8, i16, i32, u16, u32);
+big_int_conversions!(i64, u64, isize, usize);
+
+// `u8` is special-cased so that byte vectors are converted to buffers.
+impl<'env> FromNapi<'env> for u8 {
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        let number = value.as_number()?.to_f64()?;
+        let number = number_to_int(value.env(), number, "u8")?;
+
+        if number < 0.0 || number > f64::from(u8::MAX) {
+            return range_error(value.env(), "Value out of range for u8");
+        }
+
+        Ok(number as u8)
+    }
+
+    // Other typed arrays and `DataView`s are refused rather than read as
+    // bytes, which would silently reinterpret their elements.
+    fn vec_from_napi(value: NapiAny<'env>) -> NapiResult<Vec<Self>> {
+        match value.classify()? {
+            NapiDowncast::Buffer(buffer) => Ok(buffer.bytes()?.to_vec()),
+            NapiDowncast::ArrayBuffer(buffer) => {
+                Ok(buffer.bytes()?.to_vec())
+            }
+            NapiDowncast::Array(array) => {
+                let len = array.len()?;
+
+                (0..len)
+                    .map(|index| u8::from_napi(array.get(index)?))
+                    .collect()
+            }
+            _ => {
+                let message = NapiString::from_str(
+                    value.env(),
+                    "Buffer, Uint8Array, ArrayBuffer or array expected",
+                )?;
+                Err(NapiError::type_error(value.env(), &message))
+            }
+        }
+    }
+}
+
+impl<'env> ToNapi<'env> for u8 {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        NapiNumber::from_f64(env, f64::from(*self))
+            .map(|value| value.as_napi_any())
+    }
+
+    fn slice_to_napi(
+        slice: &[Self],
+        env: &'env NapiEnv,
+    ) -> NapiResult<NapiAny<'env>> {
+        NapiBuffer::from_slice(env, slice).map(|value| value.as_napi_any())
+    }
+}
+
+#[cfg(feature = "napi6")]
+impl<'env> FromNapi<'env> for i128 {
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        if value.value_type()? == NapiValueType::BigInt {
+            let (number, lossless) = value.as_bigint()?.to_i128()?;
+            if !lossless {
+                return range_error(value.env(), "Value out of range for i128");
+            }
+            return Ok(number);
+        }
+
+        let number = value.as_number()?.to_f64()?;
+        number_to_int(value.env(), number, "i128").map(|number| number as i128)
+    }
+}
+
+#[cfg(feature = "napi6")]
+impl<'env> ToNapi<'env> for i128 {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        NapiBigInt::from_i128(env, *self).map(|value| value.as_napi_any())
+    }
+}
+
+#[cfg(feature = "napi6")]
+impl<'env> FromNapi<'env> for u128 {
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        if value.value_type()? == NapiValueType::BigInt {
+            let (number, lossless) = value.as_bigint()?.to_u128()?;
+            if !lossless {
+                return range_error(value.env(), "Value out of range for u128");
+            }
+            return Ok(number);
+        }
+
+        let number = value.as_number()?.to_f64()?;
+        let number = number_to_int(value.env(), number, "u128")?;
+        if number < 0.0 {
+            return range_error(value.env(), "Value out of range for u128");
+        }
+
+        Ok(number as u128)
+    }
+}
+
+#[cfg(feature = "napi6")]
+impl<'env> ToNapi<'env> for u128 {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        NapiBigInt::from_u128(env, *self).map(|value| value.as_napi_any())
+    }
+}
+
+impl<'env> FromNapi<'env> for f64 {
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        value.as_number()?.to_f64()
+    }
+}
+
+impl<'env> ToNapi<'env> for f64 {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        NapiNumber::from_f64(env, *self).map(|value| value.as_napi_any())
+    }
+}
+
+impl<'env> FromNapi<'env> for f32 {
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        value.as_number()?.to_f64().map(|number| number as f32)
+    }
+}
+
+impl<'env> ToNapi<'env> for f32 {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        NapiNumber::from_f64(env, f64::from(*self))
+            .map(|value| value.as_napi_any())
+    }
+}
+
+impl<'env> FromNapi<'env> for String {
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        value.as_string()?.to_string()
+    }
+}
+
+impl<'env> ToNapi<'env> for String {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        self.as_str().to_napi(env)
+    }
+}
+
+impl<'env> ToNapi<'env> for str {
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        NapiString::from_str(env, self).map(|value| value.as_napi_any())
+    }
+}
+
+impl<'env, T> FromNapi<'env> for Option<T>
+where
+    T: FromNapi<'env>,
+{
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        match value.value_type()? {
+            NapiValueType::Undefined | NapiValueType::Null => Ok(None),
+            _ => T::from_napi(value).map(Some),
+        }
+    }
+}
+
+impl<'env, T> ToNapi<'env> for Option<T>
+where
+    T: ToNapi<'env>,
+{
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        match *self {
+            Some(ref value) => value.to_napi(env),
+            None => NapiNull::new(env).map(|value| value.as_napi_any()),
+        }
+    }
+}
+
+impl<'env, T> FromNapi<'env> for Vec<T>
+where
+    T: FromNapi<'env>,
+{
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        T::vec_from_napi(value)
+    }
+}
+
+impl<'env, T> ToNapi<'env> for Vec<T>
+where
+    T: ToNapi<'env>,
+{
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        T::slice_to_napi(self, env)
+    }
+}
+
+impl<'env, T> ToNapi<'env> for [T]
+where
+    T: ToNapi<'env>,
+{
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        T::slice_to_napi(self, env)
+    }
+}
+
+macro_rules! tuple_conversions {
+    ($(($len:expr => $($name:ident : $index:tt),+)),*) => {
+        $(
+            impl<'env, $($name),+> FromNapi<'env> for ($($name,)+)
+            where
+                $($name: FromNapi<'env>),+
+            {
+                fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+                    let array = value.as_array()?;
+                    if array.len()? != $len {
+                        let message = NapiString::from_str(
+                            value.env(),
+                            concat!("Array of length ", $len, " expected"),
+                        )?;
+                        return Err(NapiError::type_error(
+                            value.env(),
+                            &message,
+                        ));
+                    }
+
+                    Ok(($($name::from_napi(array.get($index)?)?,)+))
+                }
+            }
+
+            impl<'env, $($name),+> ToNapi<'env> for ($($name,)+)
+            where
+                $($name: ToNapi<'env>),+
+            {
+                fn to_napi(
+                    &self,
+                    env: &'env NapiEnv,
+                ) -> NapiResult<NapiAny<'env>> {
+                    let array = NapiArray::with_len(env, $len)?;
+                    $(array.set($index, &self.$index.to_napi(env)?)?;)+
+                    Ok(array.as_napi_any())
+                }
+            }
+        )*
+    };
+}
+
+tuple_conversions!(
+    (1 => A: 0),
+    (2 => A: 0, B: 1),
+    (3 => A: 0, B: 1, C: 2),
+    (4 => A: 0, B: 1, C: 2, D: 3),
+    (5 => A: 0, B: 1, C: 2, D: 3, E: 4),
+    (6 => A: 0, B: 1, C: 2, D: 3, E: 4, F: 5),
+    (7 => A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6),
+    (8 => A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7)
+);
+
+fn object_entries<'env, T>(
+    value: NapiAny<'env>,
+) -> NapiResult<Vec<(String, T)>>
+where
+    T: FromNapi<'env>,
+{
+    let object = value.as_object()?;
+    let names = object.property_names()?;
+    let len = names.len()?;
+    let mut entries = Vec::new();
+
+    // The names include those of enumerable inherited properties, which
+    // aren't entries of the object.
+    for index in 0..len {
+        let key = names.get(index)?;
+        if !object.has_own_property(&key)? {
+            continue;
+        }
+
+        let value = T::from_napi(object.get_property(&key)?)?;
+        let key = key.to_napi_string()?.to_string()?;
+        entries.push((key, value));
+    }
+
+    Ok(entries)
+}
+
+fn entries_to_object<'env, 'a, I, T>(
+    env: &'env NapiEnv,
+    entries: I,
+) -> NapiResult<NapiAny<'env>>
+where
+    I: Iterator<Item = (&'a String, &'a T)>,
+    T: ToNapi<'env> + 'a,
+{
+    let object = NapiObject::new(env)?;
+
+    for (key, value) in entries {
+        object.set_named_property(key, &value.to_napi(env)?)?;
+    }
+
+    Ok(object.as_napi_any())
+}
+
+impl<'env, T, S> FromNapi<'env> for HashMap<String, T, S>
+where
+    T: FromNapi<'env>,
+    S: BuildHasher + Default,
+{
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        object_entries(value).map(|entries| entries.into_iter().collect())
+    }
+}
+
+impl<'env, T, S> ToNapi<'env> for HashMap<String, T, S>
+where
+    T: ToNapi<'env>,
+    S: BuildHasher,
+{
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        entries_to_object(env, self.iter())
+    }
+}
+
+impl<'env, T> FromNapi<'env> for BTreeMap<String, T>
+where
+    T: FromNapi<'env>,
+{
+    fn from_napi(value: NapiAny<'env>) -> NapiResult<Self> {
+        object_entries(value).map(|entries| entries.into_iter().collect())
+    }
+}
+
+impl<'env, T> ToNapi<'env> for BTreeMap<String, T>
+where
+    T: ToNapi<'env>,
+{
+    fn to_napi(&self, env: &'env NapiEnv) -> NapiResult<NapiAny<'env>> {
+        entries_to_object(env, self.iter())
+    }
+}
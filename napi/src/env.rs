@@ -1,8 +1,13 @@
-use std::ffi::CStr;
+#[cfg(feature = "napi6")]
+use std::any::Any;
+use std::ffi::{CStr, CString};
+#[cfg(feature = "napi6")]
+use std::os::raw::c_void;
 use std::ptr;
 
 use sys;
 use result::{NapiError, NapiErrorKind, NapiResult};
+use version::{NapiCapabilities, NapiNodeVersion};
 
 #[derive(Clone, Copy, Debug)]
 pub struct NapiEnv {
@@ -21,10 +26,32 @@ impl NapiEnv {
     }
 
     pub fn handle_status(&self, status: sys::napi_status) -> NapiResult<()> {
-        if status == sys::napi_status::napi_ok {
+        self.handle_status_code(status as sys::napi_status_code)
+    }
+
+    pub fn handle_status_code(
+        &self,
+        status: sys::napi_status_code,
+    ) -> NapiResult<()> {
+        if status == sys::napi_status::napi_ok as sys::napi_status_code {
             return Ok(());
         }
 
+        // There is no error info to read, since the function was not even
+        // called.
+        #[cfg(feature = "dynamic-symbols")]
+        {
+            if status == sys::napi_unsupported {
+                return Err(NapiError {
+                    kind: NapiErrorKind::Unsupported,
+                    message: sys::take_missing_function()
+                        .map(|name| format!("{} is missing", name)),
+                    exception: None,
+                    path: Vec::new(),
+                });
+            }
+        }
+
         let error_message = unsafe {
             let mut extended_error_info = ptr::null();
             sys::napi_get_last_error_info(self.env, &mut extended_error_info);
@@ -38,20 +65,141 @@ impl NapiEnv {
             }
         };
 
+        // Statuses that this crate does not know about are reported as
+        // generic failures, so their code is kept in the message.
+        let error_message =
+            if NapiErrorKind::from_known_status_code(status).is_some() {
+                error_message
+            } else {
+                let unknown = format!("unknown N-API status {}", status);
+                Some(match error_message {
+                    Some(message) => format!("{}, {}", message, unknown),
+                    None => unknown,
+                })
+            };
+
         Err(NapiError {
-            kind: NapiErrorKind::from_napi_status(status),
+            kind: NapiErrorKind::from_status_code(status),
             message: error_message,
             exception: self.get_pending_exception_for_status(status),
+            path: Vec::new(),
         })
     }
 
+    // The highest N-API version that the runtime supports, which may be
+    // lower than the one the addon was built for.
+    pub fn napi_version(&self) -> NapiResult<u32> {
+        let mut version = 0;
+
+        self.handle_status(unsafe {
+            sys::napi_get_version(self.env, &mut version)
+        })?;
+
+        Ok(version)
+    }
+
+    pub fn node_version(&self) -> NapiResult<NapiNodeVersion> {
+        let mut version = ptr::null();
+
+        self.handle_status(unsafe {
+            sys::napi_get_node_version(self.env, &mut version)
+        })?;
+
+        // The version is statically allocated by Node.js.
+        let version = unsafe { &*version };
+        let release = if version.release.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(version.release) }
+                .to_string_lossy()
+                .into_owned()
+        };
+
+        Ok(NapiNodeVersion {
+            major: version.major,
+            minor: version.minor,
+            patch: version.patch,
+            release,
+        })
+    }
+
+    pub fn capabilities(&self) -> NapiResult<NapiCapabilities> {
+        self.napi_version().map(NapiCapabilities::of_runtime)
+    }
+
+    // Associates `data` with this instance of the addon.  Setting it again
+    // leaks the previous data, which may still be borrowed.
+    #[cfg(feature = "napi6")]
+    pub fn set_instance_data<T>(&self, data: T) -> NapiResult<()>
+    where
+        T: Any,
+    {
+        let data = Box::into_raw(Box::new(Box::new(data) as Box<dyn Any>));
+
+        self.handle_status_code(unsafe {
+            sys::napi_set_instance_data(
+                self.env,
+                data as *mut c_void,
+                Some(drop_instance_data),
+                ptr::null_mut(),
+            )
+        })
+        .inspect_err(|_| {
+            drop(unsafe { Box::from_raw(data) });
+        })
+    }
+
+    // Returns the data set with `set_instance_data`, unless there is none or
+    // it is not a `T`.
+    #[cfg(feature = "napi6")]
+    pub fn instance_data<T>(&self) -> NapiResult<Option<&T>>
+    where
+        T: Any,
+    {
+        let mut data = ptr::null_mut();
+
+        self.handle_status_code(unsafe {
+            sys::napi_get_instance_data(self.env, &mut data)
+        })?;
+
+        if data.is_null() {
+            return Ok(None);
+        }
+
+        let data = unsafe { &*(data as *const Box<dyn Any>) };
+        Ok(data.downcast_ref())
+    }
+
+    // Throws the JavaScript exception attached to `error`, or a new `Error`
+    // with its message if there is none.
+    pub fn throw(&self, error: NapiError) {
+        let error = error.with_path_in_message(self);
+
+        if let Some(exception) = error.exception {
+            unsafe {
+                sys::napi_throw(self.env, exception);
+            }
+            return;
+        }
+
+        let message = CString::new(error.to_string()).unwrap_or_else(|_| {
+            CString::new(error.kind.description()).unwrap()
+        });
+
+        unsafe {
+            sys::napi_throw_error(self.env, ptr::null(), message.as_ptr());
+        }
+    }
+
     fn get_pending_exception_for_status(
         &self,
-        status: sys::napi_status,
+        status: sys::napi_status_code,
     ) -> Option<sys::napi_value> {
         let mut is_exception_pending = true;
 
-        if status != sys::napi_status::napi_pending_exception {
+        if status
+            != sys::napi_status::napi_pending_exception as sys::napi_status_code
+        {
             unsafe {
                 sys::napi_is_exception_pending(
                     self.env,
@@ -76,3 +224,12 @@ impl NapiEnv {
         }
     }
 }
+
+#[cfg(feature = "napi6")]
+unsafe extern "C" fn drop_instance_data(
+    _: sys::napi_env,
+    data: *mut c_void,
+    _: *mut c_void,
+) {
+    drop(Box::from_raw(data as *mut Box<dyn Any>));
+}
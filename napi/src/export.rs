@@ -0,0 +1,43 @@
+use callback::NapiCallback;
+use env::NapiEnv;
+use result::NapiResult;
+use sys;
+use value::{NapiFunction, NapiObject, NapiValue};
+
+// A function exported from the addon, as generated by `#[napi]`.
+#[derive(Clone, Copy, Debug)]
+pub struct NapiExport {
+    pub name: &'static str,
+    pub callback: NapiCallback,
+}
+
+impl NapiExport {
+    pub fn define<'env>(&self, exports: &NapiObject<'env>) -> NapiResult<()> {
+        let function =
+            NapiFunction::new(exports.env(), self.name, self.callback)?;
+        exports.set_named_property(self.name, &function)
+    }
+}
+
+// Defines `entries` on the `exports` object of the module that is being
+// loaded.  This is the body of the `napi_register_module_v1` function
+// generated by `napi_module!`.
+#[doc(hidden)]
+#[allow(clippy::not_unsafe_ptr_arg_deref)]
+pub fn register_module(
+    env: sys::napi_env,
+    exports: sys::napi_value,
+    entries: &[NapiExport],
+) -> sys::napi_value {
+    let env = NapiEnv::from(env);
+
+    let result = NapiObject::from_sys_checked(&env, exports).and_then(
+        |exports| entries.iter().try_for_each(|entry| entry.define(&exports)),
+    );
+
+    if let Err(error) = result {
+        env.throw(error);
+    }
+
+    exports
+}
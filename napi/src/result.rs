@@ -1,12 +1,13 @@
 use std::error::Error;
 use std::fmt;
 use std::fmt::Display;
+use std::mem;
 use std::ptr;
 
 use env::NapiEnv;
-use sys::{napi_create_error, napi_create_range_error, napi_create_type_error,
-          napi_status, napi_value};
-use value::{NapiString, NapiValue};
+use sys::{self, napi_create_error, napi_create_range_error,
+          napi_create_type_error, napi_status, napi_status_code, napi_value};
+use value::{AsNapiObject, NapiErrorObject, NapiString, NapiValue};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum NapiErrorKind {
@@ -22,6 +23,19 @@ pub enum NapiErrorKind {
     PendingException,
     Cancelled,
     EscapeCalledTwice,
+    HandleScopeMismatch,
+    CallbackScopeMismatch,
+    QueueFull,
+    Closing,
+    BigintExpected,
+    DateExpected,
+    ArraybufferExpected,
+    DetachableArraybufferExpected,
+    WouldDeadlock,
+    NoExternalBuffersAllowed,
+    CannotRunJs,
+    InvalidDate,
+    Unsupported,
     ApplicationError,
 }
 
@@ -30,6 +44,16 @@ pub struct NapiError {
     pub kind: NapiErrorKind,
     pub message: Option<String>,
     pub exception: Option<napi_value>,
+    // Where the error happened in the value being converted, outermost
+    // first.  It is only added to the message when the error is given a
+    // context or thrown.
+    pub path: Vec<PathSegment>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    // The name of an object field.
+    Field(String),
 }
 
 pub type NapiResult<T> = Result<T, NapiError>;
@@ -67,11 +91,50 @@ impl NapiErrorKind {
             }
         }
     }
-}
 
-impl Error for NapiError {
-    fn description(&self) -> &str {
-        match self.kind {
+    // Statuses unknown to this crate, which newer runtimes may well return,
+    // are reported as generic failures.
+    pub fn from_status_code(code: napi_status_code) -> Self {
+        Self::from_known_status_code(code)
+            .unwrap_or(NapiErrorKind::GenericFailure)
+    }
+
+    pub(crate) fn from_known_status_code(
+        code: napi_status_code,
+    ) -> Option<Self> {
+        Some(match code {
+            sys::napi_handle_scope_mismatch => {
+                NapiErrorKind::HandleScopeMismatch
+            }
+            sys::napi_callback_scope_mismatch => {
+                NapiErrorKind::CallbackScopeMismatch
+            }
+            sys::napi_queue_full => NapiErrorKind::QueueFull,
+            sys::napi_closing => NapiErrorKind::Closing,
+            sys::napi_bigint_expected => NapiErrorKind::BigintExpected,
+            sys::napi_date_expected => NapiErrorKind::DateExpected,
+            sys::napi_arraybuffer_expected => {
+                NapiErrorKind::ArraybufferExpected
+            }
+            sys::napi_detachable_arraybuffer_expected => {
+                NapiErrorKind::DetachableArraybufferExpected
+            }
+            sys::napi_would_deadlock => NapiErrorKind::WouldDeadlock,
+            sys::napi_no_external_buffers_allowed => {
+                NapiErrorKind::NoExternalBuffersAllowed
+            }
+            sys::napi_cannot_run_js => NapiErrorKind::CannotRunJs,
+            sys::napi_unsupported => NapiErrorKind::Unsupported,
+            _ => {
+                return napi_status_from_code(code)
+                    .filter(|&status| status != napi_status::napi_ok)
+                    .map(NapiErrorKind::from_napi_status);
+            }
+        })
+    }
+
+    pub fn description(&self) -> &'static str {
+        match *self {
             NapiErrorKind::InvalidArg => "NapiError: invalid argument",
             NapiErrorKind::ObjectExpected => "NapiError: object expected",
             NapiErrorKind::StringExpected => "NapiError: string expected",
@@ -86,29 +149,103 @@ impl Error for NapiError {
             NapiErrorKind::EscapeCalledTwice => {
                 "NapiError: escape called twice"
             }
+            NapiErrorKind::HandleScopeMismatch => {
+                "NapiError: handle scope mismatch"
+            }
+            NapiErrorKind::CallbackScopeMismatch => {
+                "NapiError: callback scope mismatch"
+            }
+            NapiErrorKind::QueueFull => "NapiError: queue full",
+            NapiErrorKind::Closing => "NapiError: closing",
+            NapiErrorKind::BigintExpected => "NapiError: bigint expected",
+            NapiErrorKind::DateExpected => "NapiError: date expected",
+            NapiErrorKind::ArraybufferExpected => {
+                "NapiError: arraybuffer expected"
+            }
+            NapiErrorKind::DetachableArraybufferExpected => {
+                "NapiError: detachable arraybuffer expected"
+            }
+            NapiErrorKind::WouldDeadlock => "NapiError: would deadlock",
+            NapiErrorKind::NoExternalBuffersAllowed => {
+                "NapiError: no external buffers allowed"
+            }
+            NapiErrorKind::CannotRunJs => "NapiError: cannot run JavaScript",
+            NapiErrorKind::InvalidDate => "NapiError: invalid date",
+            NapiErrorKind::Unsupported => {
+                "NapiError: unsupported by this runtime"
+            }
             NapiErrorKind::ApplicationError => "NapiError: application error",
         }
     }
 }
 
+fn napi_status_from_code(code: napi_status_code) -> Option<napi_status> {
+    let statuses = [
+        napi_status::napi_ok,
+        napi_status::napi_invalid_arg,
+        napi_status::napi_object_expected,
+        napi_status::napi_string_expected,
+        napi_status::napi_name_expected,
+        napi_status::napi_function_expected,
+        napi_status::napi_number_expected,
+        napi_status::napi_boolean_expected,
+        napi_status::napi_array_expected,
+        napi_status::napi_generic_failure,
+        napi_status::napi_pending_exception,
+        napi_status::napi_cancelled,
+        napi_status::napi_escape_called_twice,
+    ];
+
+    statuses
+        .iter()
+        .cloned()
+        .find(|&status| status as napi_status_code == code)
+}
+
+impl Error for NapiError {
+    fn description(&self) -> &str {
+        self.kind.description()
+    }
+}
+
 impl Display for NapiError {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "{}", self.description())
-            .and_then(|result| {
-                if let Some(ref message) = self.message {
-                    write!(formatter, " ({})", message)
-                } else {
-                    Ok(result)
-                }
-            })
-            .and_then(|result| {
-                if self.exception.is_some() {
-                    write!(formatter, ", JavaScript exception attached")
-                } else {
-                    Ok(result)
+        write!(formatter, "{}", self.kind.description())?;
+
+        let path = format_path(&self.path);
+        match self.message {
+            Some(ref message) if path.is_empty() => {
+                write!(formatter, " ({})", message)?
+            }
+            Some(ref message) => write!(formatter, " ({}: {})", path, message)?,
+            None if !path.is_empty() => write!(formatter, " ({})", path)?,
+            None => {}
+        }
+
+        if self.exception.is_some() {
+            write!(formatter, ", JavaScript exception attached")?;
+        }
+
+        Ok(())
+    }
+}
+
+// Joins the names of nested fields with dots, as in `options.timeout`.
+fn format_path(path: &[PathSegment]) -> String {
+    let mut formatted = String::new();
+
+    for segment in path {
+        match *segment {
+            PathSegment::Field(ref name) => {
+                if !formatted.is_empty() {
+                    formatted.push('.');
                 }
-            })
+                formatted.push_str(name);
+            }
+        }
     }
+
+    formatted
 }
 
 macro_rules! error_constructor {
@@ -132,6 +269,7 @@ macro_rules! error_constructor {
                 kind: NapiErrorKind::ApplicationError,
                 message: None,
                 exception: Some(exception),
+                path: Vec::new(),
             }
         }
     }
@@ -141,4 +279,169 @@ impl NapiError {
     error_constructor!(error => napi_create_error);
     error_constructor!(type_error => napi_create_type_error);
     error_constructor!(range_error => napi_create_range_error);
+
+    // Whether the error is an exception that JavaScript threw, say from a
+    // getter, rather than one created to be thrown.
+    pub fn is_thrown(&self) -> bool {
+        self.exception.is_some() && self.kind != NapiErrorKind::ApplicationError
+    }
+
+    // Prefixes the error message with `context`, e.g. the name of the argument
+    // that failed to convert.  JavaScript errors are updated in place, so that
+    // they keep their class, except for those thrown by JavaScript, which are
+    // left as they are.
+    pub fn context(self, env: &NapiEnv, context: &str) -> NapiError {
+        if self.is_thrown() {
+            return self;
+        }
+
+        self.with_path_in_message(env)
+            .map_message(env, |message| match message {
+                Some(message) => format!("{}: {}", context, message),
+                None => context.to_owned(),
+            })
+    }
+
+    // Adds the name of an object field to the path of the error.
+    pub fn field_context(mut self, field: &str) -> NapiError {
+        self.path.insert(0, PathSegment::Field(field.to_owned()));
+        self
+    }
+
+    // The message that the error would be thrown with, for reporting it as
+    // part of another error.
+    pub fn thrown_message(self, env: &NapiEnv) -> String {
+        let error = self.with_path_in_message(env);
+
+        if let Some(exception) = error.exception {
+            let message = NapiErrorObject::from_sys_checked(env, exception)
+                .and_then(|exception| exception.message());
+            if let Ok(message) = message {
+                return message;
+            }
+        }
+
+        error.to_string()
+    }
+
+    // Moves the path into the message, as in `options.timeout: number
+    // expected`.
+    pub(crate) fn with_path_in_message(mut self, env: &NapiEnv) -> NapiError {
+        if self.path.is_empty() || self.is_thrown() {
+            return self;
+        }
+
+        let path = format_path(&mem::take(&mut self.path));
+        self.map_message(env, |message| match message {
+            Some(message) => format!("{}: {}", path, message),
+            None => path,
+        })
+    }
+
+    fn map_message<F>(mut self, env: &NapiEnv, f: F) -> NapiError
+    where
+        F: FnOnce(Option<&str>) -> String,
+    {
+        if let Some(exception) = self.exception {
+            if let Ok(error) = NapiErrorObject::from_sys_checked(env, exception)
+            {
+                let updated = error.message().and_then(|message| {
+                    error.as_napi_object().set_named_property(
+                        "message",
+                        &NapiString::from_str(env, &f(Some(&message)))?,
+                    )
+                });
+
+                if let Err(error) = updated {
+                    return error;
+                }
+            }
+
+            return self;
+        }
+
+        self.message = Some(f(self.message.as_deref()));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_known_status_codes() {
+        let kinds = [
+            (1, NapiErrorKind::InvalidArg),
+            (12, NapiErrorKind::EscapeCalledTwice),
+            (13, NapiErrorKind::HandleScopeMismatch),
+            (14, NapiErrorKind::CallbackScopeMismatch),
+            (15, NapiErrorKind::QueueFull),
+            (16, NapiErrorKind::Closing),
+            (17, NapiErrorKind::BigintExpected),
+            (18, NapiErrorKind::DateExpected),
+            (19, NapiErrorKind::ArraybufferExpected),
+            (20, NapiErrorKind::DetachableArraybufferExpected),
+            (21, NapiErrorKind::WouldDeadlock),
+            (22, NapiErrorKind::NoExternalBuffersAllowed),
+            (23, NapiErrorKind::CannotRunJs),
+        ];
+
+        for &(code, kind) in &kinds {
+            assert_eq!(NapiErrorKind::from_status_code(code), kind);
+        }
+    }
+
+    #[test]
+    fn maps_unknown_status_codes_to_generic_failures() {
+        for &code in &[0, 24, 1000] {
+            assert_eq!(NapiErrorKind::from_known_status_code(code), None);
+            assert_eq!(
+                NapiErrorKind::from_status_code(code),
+                NapiErrorKind::GenericFailure
+            );
+        }
+    }
+
+    #[test]
+    fn formats_paths_once() {
+        let error = NapiError {
+            kind: NapiErrorKind::InvalidDate,
+            message: Some("1e300 is out of range".to_owned()),
+            exception: None,
+            path: Vec::new(),
+        };
+        let error = error.field_context("start time").field_context("range");
+
+        assert_eq!(
+            error.path,
+            vec![
+                PathSegment::Field("range".to_owned()),
+                PathSegment::Field("start time".to_owned()),
+            ]
+        );
+        assert_eq!(
+            error.to_string(),
+            "NapiError: invalid date (range.start time: 1e300 is out of range)"
+        );
+    }
+
+    #[test]
+    fn tells_thrown_exceptions_apart() {
+        let exception = ptr::NonNull::dangling().as_ptr();
+        let error = |kind, exception| NapiError {
+            kind,
+            message: None,
+            exception,
+            path: Vec::new(),
+        };
+
+        assert!(error(NapiErrorKind::PendingException, Some(exception))
+            .is_thrown());
+        assert!(error(NapiErrorKind::GenericFailure, Some(exception))
+            .is_thrown());
+        assert!(!error(NapiErrorKind::ApplicationError, Some(exception))
+            .is_thrown());
+        assert!(!error(NapiErrorKind::GenericFailure, None).is_thrown());
+    }
 }
@@ -0,0 +1,333 @@
+#![allow(non_upper_case_globals)]
+#![allow(non_camel_case_types)]
+
+pub use napi_sys::*;
+
+// Declares N-API functions, or with the `dynamic-symbols` feature, defines
+// them to look the actual functions up on first use.
+macro_rules! napi_functions {
+    ($(
+        $(#[$attr:meta])*
+        pub fn $name:ident($($arg:ident: $ty:ty),* $(,)*) -> napi_status_code;
+    )*) => {
+        $(
+            #[cfg(not(feature = "dynamic-symbols"))]
+            $(#[$attr])*
+            extern "C" {
+                pub fn $name($($arg: $ty),*) -> napi_status_code;
+            }
+
+            #[cfg(feature = "dynamic-symbols")]
+            $(#[$attr])*
+            #[allow(clippy::missing_safety_doc)]
+            pub unsafe fn $name($($arg: $ty),*) -> napi_status_code {
+                type Function =
+                    unsafe extern "C" fn($($ty),*) -> napi_status_code;
+
+                static ADDRESS: dynamic::Address = dynamic::Address::new();
+
+                match ADDRESS.resolve(concat!(stringify!($name), "\0")) {
+                    Some(address) => {
+                        let function: Function = ::std::mem::transmute(address);
+                        function($($arg),*)
+                    }
+                    None => napi_unsupported,
+                }
+            }
+        )*
+    };
+}
+
+// N-API functions and status codes that are missing from the headers
+// `napi-sys` was generated from.  Functions that may fail with one of the newer
+// status codes return a plain `napi_status_code`, since an unknown value can't
+// be safely read as a `napi_status`.
+
+pub type napi_status_code = u32;
+
+pub const napi_handle_scope_mismatch: napi_status_code = 13;
+pub const napi_callback_scope_mismatch: napi_status_code = 14;
+pub const napi_queue_full: napi_status_code = 15;
+pub const napi_closing: napi_status_code = 16;
+pub const napi_bigint_expected: napi_status_code = 17;
+pub const napi_date_expected: napi_status_code = 18;
+pub const napi_arraybuffer_expected: napi_status_code = 19;
+pub const napi_detachable_arraybuffer_expected: napi_status_code = 20;
+pub const napi_would_deadlock: napi_status_code = 21;
+pub const napi_no_external_buffers_allowed: napi_status_code = 22;
+pub const napi_cannot_run_js: napi_status_code = 23;
+
+// Likewise, `napi_typeof` can report value types unknown to `napi_valuetype`.
+
+pub type napi_valuetype_code = u32;
+
+pub const napi_bigint: napi_valuetype_code = 9;
+
+extern "C" {
+    #[link_name = "napi_typeof"]
+    pub fn napi_typeof_code(
+        env: napi_env,
+        value: napi_value,
+        result: *mut napi_valuetype_code,
+    ) -> napi_status;
+}
+
+// And `napi_get_typedarray_info` can report types of typed arrays unknown to
+// `napi_typedarray_type`.
+
+pub type napi_typedarray_type_code = u32;
+
+pub const napi_bigint64_array: napi_typedarray_type_code = 9;
+pub const napi_biguint64_array: napi_typedarray_type_code = 10;
+
+extern "C" {
+    #[link_name = "napi_get_typedarray_info"]
+    pub fn napi_get_typedarray_info_code(
+        env: napi_env,
+        typedarray: napi_value,
+        type_: *mut napi_typedarray_type_code,
+        length: *mut usize,
+        data: *mut *mut ::std::os::raw::c_void,
+        arraybuffer: *mut napi_value,
+        byte_offset: *mut usize,
+    ) -> napi_status;
+}
+
+// The functions are only declared when the `napiN` feature of the version
+// that added them is enabled, so that using them with an older version fails
+// to compile rather than to load.  With the `dynamic-symbols` feature, they
+// are instead looked up in the process the first time they are called, and
+// return `napi_unsupported`, which is not an actual N-API status, if it does
+// not have them.  They all return a `napi_status_code` for that reason.
+
+pub const napi_unsupported: napi_status_code = !0;
+
+napi_functions! {
+    #[cfg(feature = "napi7")]
+    pub fn napi_is_detached_arraybuffer(
+        env: napi_env,
+        value: napi_value,
+        result: *mut bool,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi7")]
+    pub fn napi_detach_arraybuffer(
+        env: napi_env,
+        arraybuffer: napi_value,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi9")]
+    pub fn node_api_symbol_for(
+        env: napi_env,
+        utf8description: *const ::std::os::raw::c_char,
+        length: usize,
+        result: *mut napi_value,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi6")]
+    pub fn napi_create_bigint_int64(
+        env: napi_env,
+        value: i64,
+        result: *mut napi_value,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi6")]
+    pub fn napi_create_bigint_uint64(
+        env: napi_env,
+        value: u64,
+        result: *mut napi_value,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi6")]
+    pub fn napi_create_bigint_words(
+        env: napi_env,
+        sign_bit: ::std::os::raw::c_int,
+        word_count: usize,
+        words: *const u64,
+        result: *mut napi_value,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi6")]
+    pub fn napi_get_value_bigint_int64(
+        env: napi_env,
+        value: napi_value,
+        result: *mut i64,
+        lossless: *mut bool,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi6")]
+    pub fn napi_get_value_bigint_uint64(
+        env: napi_env,
+        value: napi_value,
+        result: *mut u64,
+        lossless: *mut bool,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi6")]
+    pub fn napi_get_value_bigint_words(
+        env: napi_env,
+        value: napi_value,
+        sign_bit: *mut ::std::os::raw::c_int,
+        word_count: *mut usize,
+        words: *mut u64,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi5")]
+    pub fn napi_create_date(
+        env: napi_env,
+        time: f64,
+        result: *mut napi_value,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi5")]
+    pub fn napi_is_date(
+        env: napi_env,
+        value: napi_value,
+        is_date: *mut bool,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi5")]
+    pub fn napi_get_date_value(
+        env: napi_env,
+        value: napi_value,
+        result: *mut f64,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi5")]
+    pub fn napi_add_finalizer(
+        env: napi_env,
+        js_object: napi_value,
+        finalize_data: *mut ::std::os::raw::c_void,
+        finalize_cb: napi_finalize,
+        finalize_hint: *mut ::std::os::raw::c_void,
+        result: *mut napi_ref,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi6")]
+    pub fn napi_set_instance_data(
+        env: napi_env,
+        data: *mut ::std::os::raw::c_void,
+        finalize_cb: napi_finalize,
+        finalize_hint: *mut ::std::os::raw::c_void,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi6")]
+    pub fn napi_get_instance_data(
+        env: napi_env,
+        data: *mut *mut ::std::os::raw::c_void,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi8")]
+    pub fn napi_type_tag_object(
+        env: napi_env,
+        value: napi_value,
+        type_tag: *const napi_type_tag,
+    ) -> napi_status_code;
+
+    #[cfg(feature = "napi8")]
+    pub fn napi_check_object_type_tag(
+        env: napi_env,
+        value: napi_value,
+        type_tag: *const napi_type_tag,
+        result: *mut bool,
+    ) -> napi_status_code;
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct napi_type_tag {
+    pub lower: u64,
+    pub upper: u64,
+}
+
+// Looks the functions up in the process that loaded the addon, which is
+// where Node.js exports them from.  Nothing is looked up unless one of the
+// `napiN` features from `napi5` on is enabled too.
+#[cfg(feature = "dynamic-symbols")]
+#[allow(dead_code)]
+mod dynamic {
+    use std::cell::Cell;
+    use std::ffi::CString;
+    use std::os::raw::{c_char, c_void};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Neither a function nor null, which is what a missing one resolves to.
+    const UNRESOLVED: usize = 1;
+
+    thread_local! {
+        static MISSING: Cell<Option<&'static str>> = const { Cell::new(None) };
+    }
+
+    // The address of a function, resolved on first use.
+    pub struct Address(AtomicUsize);
+
+    impl Address {
+        pub const fn new() -> Self {
+            Address(AtomicUsize::new(UNRESOLVED))
+        }
+
+        // `name` must be nul-terminated.
+        pub fn resolve(&self, name: &'static str) -> Option<*mut c_void> {
+            let mut address = self.0.load(Ordering::Relaxed);
+            if address == UNRESOLVED {
+                address = unsafe { lookup(name.as_ptr() as *const c_char) }
+                    as usize;
+                self.0.store(address, Ordering::Relaxed);
+            }
+
+            if address == 0 {
+                let name = &name[..name.len() - 1];
+                MISSING.with(|missing| missing.set(Some(name)));
+                None
+            } else {
+                Some(address as *mut c_void)
+            }
+        }
+    }
+
+    // Whether the process has the function of the given name.
+    pub fn has_function(name: &str) -> bool {
+        match CString::new(name) {
+            Ok(name) => !unsafe { lookup(name.as_ptr()) }.is_null(),
+            Err(_) => false,
+        }
+    }
+
+    // The name of the function that was last found missing on this thread.
+    pub fn take_missing_function() -> Option<&'static str> {
+        MISSING.with(Cell::take)
+    }
+
+    #[cfg(unix)]
+    unsafe fn lookup(name: *const c_char) -> *mut c_void {
+        extern "C" {
+            fn dlsym(handle: *mut c_void, symbol: *const c_char)
+                -> *mut c_void;
+        }
+
+        // `RTLD_DEFAULT`, which glibc and musl define as null, and the BSDs
+        // and macOS as -2.
+        let handle = if cfg!(target_os = "linux") {
+            0
+        } else {
+            -2isize as usize
+        };
+
+        dlsym(handle as *mut c_void, name)
+    }
+
+    #[cfg(windows)]
+    unsafe fn lookup(name: *const c_char) -> *mut c_void {
+        extern "system" {
+            fn GetModuleHandleW(name: *const u16) -> *mut c_void;
+            fn GetProcAddress(module: *mut c_void, name: *const c_char)
+                -> *mut c_void;
+        }
+
+        // The executable, which is `node.exe`.
+        GetProcAddress(GetModuleHandleW(::std::ptr::null()), name)
+    }
+}
+
+#[cfg(feature = "dynamic-symbols")]
+pub use self::dynamic::{has_function, take_missing_function};
@@ -0,0 +1,112 @@
+// Checks used by the code that `#[derive(NapiArgs)]` generates for the
+// validation attributes of the arguments.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::hash::BuildHasher;
+use std::ops::RangeBounds;
+
+use env::NapiEnv;
+use result::{NapiError, NapiResult};
+use value::NapiString;
+
+// The length checked by `non_empty` and `max_len`.
+pub trait Length {
+    fn length(&self) -> usize;
+}
+
+impl Length for str {
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl Length for String {
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl<T> Length for [T] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Length for Vec<T> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S: BuildHasher> Length for HashMap<K, V, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> Length for BTreeMap<K, V> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+pub fn check_range<T, R>(
+    env: &NapiEnv,
+    value: &T,
+    range: &R,
+    range_str: &str,
+) -> NapiResult<()>
+where
+    T: PartialOrd,
+    R: RangeBounds<T>,
+{
+    if range.contains(value) {
+        return Ok(());
+    }
+
+    let message =
+        NapiString::from_str(env, &format!("must be in range {}", range_str))?;
+    Err(NapiError::range_error(env, &message))
+}
+
+pub fn check_non_empty<T>(env: &NapiEnv, value: &T) -> NapiResult<()>
+where
+    T: Length + ?Sized,
+{
+    if value.length() > 0 {
+        return Ok(());
+    }
+
+    let message = NapiString::from_str(env, "must not be empty")?;
+    Err(NapiError::type_error(env, &message))
+}
+
+pub fn check_max_len<T>(env: &NapiEnv, value: &T, max: usize) -> NapiResult<()>
+where
+    T: Length + ?Sized,
+{
+    if value.length() <= max {
+        return Ok(());
+    }
+
+    let message = NapiString::from_str(
+        env,
+        &format!("must not be longer than {}", max),
+    )?;
+    Err(NapiError::range_error(env, &message))
+}
+
+// Turns the result of a `validate` function into a `TypeError`.
+pub fn check_custom<E>(env: &NapiEnv, result: Result<(), E>) -> NapiResult<()>
+where
+    E: Display,
+{
+    match result {
+        Ok(()) => Ok(()),
+        Err(error) => {
+            let message = NapiString::from_str(env, &error.to_string())?;
+            Err(NapiError::type_error(env, &message))
+        }
+    }
+}
@@ -1,12 +1,41 @@
 use std::ptr;
 
 use env::NapiEnv;
+#[cfg(feature = "napi5")]
+use result::NapiErrorKind;
 use result::{NapiError, NapiResult};
 use sys;
 
-use super::{NapiArray, NapiBoolean, NapiNull, NapiNumber, NapiObject,
-            NapiString, NapiUndefined, NapiValue, NapiValueInternal,
-            NapiValueType};
+#[cfg(feature = "napi5")]
+use super::NapiDate;
+use super::{NapiArray, NapiArrayBuffer, NapiBigInt, NapiBoolean, NapiBuffer,
+            NapiDataView, NapiErrorObject, NapiExternal, NapiFunction,
+            NapiNull, NapiNumber, NapiObject, NapiPromise, NapiString,
+            NapiSymbol, NapiTypedArray, NapiUndefined, NapiValue,
+            NapiValueInternal, NapiValueType};
+
+#[derive(Debug)]
+pub enum NapiDowncast<'env> {
+    Undefined(NapiUndefined<'env>),
+    Null(NapiNull<'env>),
+    Boolean(NapiBoolean<'env>),
+    Number(NapiNumber<'env>),
+    String(NapiString<'env>),
+    Symbol(NapiSymbol<'env>),
+    BigInt(NapiBigInt<'env>),
+    Array(NapiArray<'env>),
+    Buffer(NapiBuffer<'env>),
+    TypedArray(NapiTypedArray<'env>),
+    ArrayBuffer(NapiArrayBuffer<'env>),
+    DataView(NapiDataView<'env>),
+    #[cfg(feature = "napi5")]
+    Date(NapiDate<'env>),
+    Promise(NapiPromise<'env>),
+    Function(NapiFunction<'env>),
+    External(NapiExternal<'env>),
+    Error(NapiErrorObject<'env>),
+    Object(NapiObject<'env>),
+}
 
 #[derive(Clone, Copy, Debug)]
 pub struct NapiAny<'env> {
@@ -88,11 +117,45 @@ impl<'env> NapiAny<'env> {
         }
     }
 
+    pub fn as_symbol(&self) -> NapiResult<NapiSymbol<'env>> {
+        match self.value_type()? {
+            NapiValueType::Symbol => {
+                Ok(NapiSymbol::construct(self.env(), self.as_sys_value()))
+            }
+            _ => Err(NapiError::type_error(
+                self.env(),
+                &NapiString::from_str(self.env(), "symbol expected")?,
+            )),
+        }
+    }
+
+    pub fn as_bigint(&self) -> NapiResult<NapiBigInt<'env>> {
+        match self.value_type()? {
+            NapiValueType::BigInt => {
+                Ok(NapiBigInt::construct(self.env(), self.as_sys_value()))
+            }
+            _ => Err(NapiError::type_error(
+                self.env(),
+                &NapiString::from_str(self.env(), "bigint expected")?,
+            )),
+        }
+    }
+
+    #[cfg(feature = "napi5")]
+    pub fn as_date(&self) -> NapiResult<NapiDate<'env>> {
+        if self.is_date()? {
+            Ok(NapiDate::construct(self.env(), self.as_sys_value()))
+        } else {
+            Err(NapiError::type_error(
+                self.env(),
+                &NapiString::from_str(self.env(), "date expected")?,
+            ))
+        }
+    }
+
     pub fn as_object(&self) -> NapiResult<NapiObject<'env>> {
         match self.value_type()? {
-            NapiValueType::Object
-            | NapiValueType::String
-            | NapiValueType::Function => {
+            NapiValueType::Object => {
                 Ok(NapiObject::construct(self.env(), self.as_sys_value()))
             }
             _ => Err(NapiError::type_error(
@@ -112,6 +175,94 @@ impl<'env> NapiAny<'env> {
             ))
         }
     }
+
+    pub fn classify(&self) -> NapiResult<NapiDowncast<'env>> {
+        let env = self.env();
+        let value = self.as_sys_value();
+
+        Ok(match self.value_type()? {
+            NapiValueType::Undefined => {
+                NapiDowncast::Undefined(NapiUndefined::construct(env, value))
+            }
+            NapiValueType::Null => {
+                NapiDowncast::Null(NapiNull::construct(env, value))
+            }
+            NapiValueType::Boolean => {
+                NapiDowncast::Boolean(NapiBoolean::construct(env, value))
+            }
+            NapiValueType::Number => {
+                NapiDowncast::Number(NapiNumber::construct(env, value))
+            }
+            NapiValueType::String => {
+                NapiDowncast::String(NapiString::construct(env, value))
+            }
+            NapiValueType::Symbol => {
+                NapiDowncast::Symbol(NapiSymbol::construct(env, value))
+            }
+            NapiValueType::BigInt => {
+                NapiDowncast::BigInt(NapiBigInt::construct(env, value))
+            }
+            NapiValueType::Function => {
+                NapiDowncast::Function(NapiFunction::construct(env, value))
+            }
+            NapiValueType::External => {
+                NapiDowncast::External(NapiExternal::construct(env, value))
+            }
+            NapiValueType::Object => self.classify_object()?,
+            NapiValueType::Unknown(_) => {
+                let message =
+                    NapiString::from_str(env, "unsupported value type")?;
+                return Err(NapiError::type_error(env, &message));
+            }
+        })
+    }
+
+    fn classify_object(&self) -> NapiResult<NapiDowncast<'env>> {
+        let env = self.env();
+        let value = self.as_sys_value();
+
+        // Dates can't be told apart in runtimes that lack `napi_is_date`,
+        // so they are classified as plain objects there.
+        #[cfg(feature = "napi5")]
+        {
+            let is_date = match self.is_date() {
+                Err(ref error) if error.kind == NapiErrorKind::Unsupported => {
+                    false
+                }
+                result => result?,
+            };
+            if is_date {
+                return Ok(NapiDowncast::Date(NapiDate::construct(env, value)));
+            }
+        }
+
+        // `napi_is_buffer` can't tell buffers apart from other ArrayBuffer
+        // views, since recent Node.js versions consider any of them to be a
+        // buffer.  Node.js buffers are `Uint8Array`s, so those are classified
+        // as buffers, and the other typed arrays are not.
+        Ok(if self.is_array()? {
+            NapiDowncast::Array(NapiArray::construct(env, value))
+        } else if self.is_dataview()? {
+            NapiDowncast::DataView(NapiDataView::construct(env, value))
+        } else if self.is_typedarray()? {
+            let array = NapiTypedArray::construct(env, value);
+            let uint8 = sys::napi_typedarray_type::napi_uint8_array;
+
+            if array.array_type()? == uint8 as sys::napi_typedarray_type_code {
+                NapiDowncast::Buffer(NapiBuffer::construct(env, value))
+            } else {
+                NapiDowncast::TypedArray(array)
+            }
+        } else if self.is_arraybuffer()? {
+            NapiDowncast::ArrayBuffer(NapiArrayBuffer::construct(env, value))
+        } else if self.is_promise()? {
+            NapiDowncast::Promise(NapiPromise::construct(env, value))
+        } else if self.is_error()? {
+            NapiDowncast::Error(NapiErrorObject::construct(env, value))
+        } else {
+            NapiDowncast::Object(NapiObject::construct(env, value))
+        })
+    }
 }
 
 impl<'env> NapiValue<'env> for NapiAny<'env> {
@@ -1,20 +1,23 @@
+use std::ops::RangeBounds;
 use std::ptr;
-use std::slice;
 
 use env::NapiEnv;
-use result::{NapiError, NapiResult};
+use result::{NapiError, NapiErrorKind, NapiResult};
 use sys;
 
-use super::{AsNapiObject, NapiAny, NapiString, NapiValue};
+#[cfg(feature = "napi7")]
+use super::bytes::ensure_unborrowed;
+use super::bytes::resolve_range;
+use super::{AsNapiObject, NapiAny, NapiBytes, NapiBytesMut, NapiString,
+            NapiTypedArray, NapiValue, NapiValueInternal};
 
 #[derive(Debug)]
-pub struct NapiArrayBuffer<'env, 'buf> {
+pub struct NapiArrayBuffer<'env> {
     value: sys::napi_value,
-    data: &'buf mut [u8],
     env: &'env NapiEnv,
 }
 
-impl<'env, 'buf> NapiArrayBuffer<'env, 'buf> {
+impl<'env> NapiArrayBuffer<'env> {
     pub fn new(env: &'env NapiEnv, len: usize) -> NapiResult<Self> {
         let mut value = ptr::null_mut();
         let mut data = ptr::null_mut();
@@ -28,23 +31,140 @@ impl<'env, 'buf> NapiArrayBuffer<'env, 'buf> {
             )
         })?;
 
-        Ok(Self {
-            value,
-            data: unsafe { slice::from_raw_parts_mut(data as *mut u8, len) },
-            env,
+        // N-API does not guarantee that the memory is initialized.
+        if !data.is_null() {
+            unsafe {
+                ptr::write_bytes(data as *mut u8, 0, len);
+            }
+        }
+
+        Ok(Self { value, env })
+    }
+
+    pub fn len(&self) -> NapiResult<usize> {
+        self.info().map(|(_, len)| len)
+    }
+
+    pub fn is_empty(&self) -> NapiResult<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    pub fn bytes(&self) -> NapiResult<NapiBytes<'_>> {
+        let (data, len) = self.raw_data()?;
+        unsafe { NapiBytes::borrow(self.env, data, len) }
+    }
+
+    pub fn bytes_mut(&mut self) -> NapiResult<NapiBytesMut<'_>> {
+        let (data, len) = self.raw_data()?;
+        unsafe { NapiBytesMut::borrow(self.env, data, len) }
+    }
+
+    pub fn view<R>(&self, range: R) -> NapiResult<NapiTypedArray<'env>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = resolve_range(self.env, range, self.len()?)?;
+        let mut result = ptr::null_mut();
+
+        self.env.handle_status(unsafe {
+            sys::napi_create_typedarray(
+                self.env.as_sys_env(),
+                sys::napi_typedarray_type::napi_uint8_array,
+                end - start,
+                self.value,
+                start,
+                &mut result,
+            )
+        })?;
+
+        Ok(NapiTypedArray::construct(self.env, result))
+    }
+
+    #[cfg(feature = "napi7")]
+    pub fn is_detached(&self) -> NapiResult<bool> {
+        let mut result = false;
+
+        self.env.handle_status_code(unsafe {
+            sys::napi_is_detached_arraybuffer(
+                self.env.as_sys_env(),
+                self.value,
+                &mut result,
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    #[cfg(feature = "napi7")]
+    pub fn detach(&mut self) -> NapiResult<()> {
+        let (data, len) = self.info()?;
+        ensure_unborrowed(self.env, data, len)?;
+
+        self.env.handle_status_code(unsafe {
+            sys::napi_detach_arraybuffer(self.env.as_sys_env(), self.value)
         })
     }
 
-    pub fn len(&self) -> usize {
-        self.data.len()
+    // Fails if the ArrayBuffer is detached, which is how views of it can
+    // tell that their memory is gone.
+    pub(crate) fn ensure_attached(&self) -> NapiResult<()> {
+        self.raw_data().map(|_| ())
+    }
+
+    fn raw_data(&self) -> NapiResult<(*mut u8, usize)> {
+        let (data, len) = self.info()?;
+
+        if self.detached(data, len)? {
+            return Err(NapiError {
+                kind: NapiErrorKind::DetachableArraybufferExpected,
+                message: Some("ArrayBuffer is detached".to_owned()),
+                exception: None,
+                path: Vec::new(),
+            });
+        }
+
+        Ok((data, len))
+    }
+
+    // Runtimes that lack `napi_is_detached_arraybuffer` under
+    // `dynamic-symbols` can only be asked for the data, which detached
+    // ArrayBuffers report as null and empty.  So may empty ArrayBuffers, which
+    // are then treated as detached as well.  Builds without `napi7` cannot
+    // detach ArrayBuffers, and read those detached by JavaScript as empty.
+    #[cfg(feature = "napi7")]
+    fn detached(&self, data: *mut u8, len: usize) -> NapiResult<bool> {
+        match self.is_detached() {
+            Err(ref error) if error.kind == NapiErrorKind::Unsupported => {
+                Ok(data.is_null() && len == 0)
+            }
+            result => result,
+        }
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+    #[cfg(not(feature = "napi7"))]
+    fn detached(&self, _: *mut u8, _: usize) -> NapiResult<bool> {
+        Ok(false)
+    }
+
+    // Detached ArrayBuffers are reported as empty, as in JavaScript.
+    fn info(&self) -> NapiResult<(*mut u8, usize)> {
+        let mut data = ptr::null_mut();
+        let mut len = 0;
+
+        self.env.handle_status(unsafe {
+            sys::napi_get_arraybuffer_info(
+                self.env.as_sys_env(),
+                self.value,
+                &mut data,
+                &mut len,
+            )
+        })?;
+
+        Ok((data as *mut u8, len))
     }
 }
 
-impl<'env, 'buf> NapiValue<'env> for NapiArrayBuffer<'env, 'buf> {
+impl<'env> NapiValue<'env> for NapiArrayBuffer<'env> {
     fn as_sys_value(&self) -> sys::napi_value {
         self.value
     }
@@ -62,36 +182,14 @@ impl<'env, 'buf> NapiValue<'env> for NapiArrayBuffer<'env, 'buf> {
             return Err(NapiError::type_error(env, &message));
         }
 
-        let mut data = ptr::null_mut();
-        let mut len = 0;
-
-        env.handle_status(unsafe {
-            sys::napi_get_arraybuffer_info(
-                env.as_sys_env(),
-                value,
-                &mut data,
-                &mut len,
-            )
-        })?;
-
-        Ok(Self {
-            env,
-            value,
-            data: unsafe { slice::from_raw_parts_mut(data as *mut u8, len) },
-        })
+        Ok(Self { env, value })
     }
 }
 
-impl<'env, 'buf> AsNapiObject<'env> for NapiArrayBuffer<'env, 'buf> {}
-
-impl<'env, 'buf> AsRef<[u8]> for NapiArrayBuffer<'env, 'buf> {
-    fn as_ref(&self) -> &[u8] {
-        self.data
+impl<'env> NapiValueInternal<'env> for NapiArrayBuffer<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
     }
 }
 
-impl<'env, 'buf> AsMut<[u8]> for NapiArrayBuffer<'env, 'buf> {
-    fn as_mut(&mut self) -> &mut [u8] {
-        self.data
-    }
-}
+impl<'env> AsNapiObject<'env> for NapiArrayBuffer<'env> {}
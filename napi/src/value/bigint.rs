@@ -0,0 +1,222 @@
+#[cfg(feature = "napi6")]
+use std::ptr;
+
+use env::NapiEnv;
+use result::{NapiError, NapiResult};
+use sys;
+
+use super::{NapiAny, NapiString, NapiValue, NapiValueInternal, NapiValueType};
+
+#[derive(Clone, Copy, Debug)]
+pub struct NapiBigInt<'env> {
+    value: sys::napi_value,
+    env: &'env NapiEnv,
+}
+
+// BigInts can be told apart with any version, but only created and read
+// since N-API 6.
+#[cfg(feature = "napi6")]
+impl<'env> NapiBigInt<'env> {
+    pub fn from_i64(env: &'env NapiEnv, value: i64) -> NapiResult<Self> {
+        let mut sys_value = ptr::null_mut();
+        env.handle_status_code(unsafe {
+            sys::napi_create_bigint_int64(
+                env.as_sys_env(),
+                value,
+                &mut sys_value,
+            )
+        })?;
+
+        Ok(Self {
+            value: sys_value,
+            env,
+        })
+    }
+
+    pub fn from_u64(env: &'env NapiEnv, value: u64) -> NapiResult<Self> {
+        let mut sys_value = ptr::null_mut();
+        env.handle_status_code(unsafe {
+            sys::napi_create_bigint_uint64(
+                env.as_sys_env(),
+                value,
+                &mut sys_value,
+            )
+        })?;
+
+        Ok(Self {
+            value: sys_value,
+            env,
+        })
+    }
+
+    pub fn from_i128(env: &'env NapiEnv, value: i128) -> NapiResult<Self> {
+        let magnitude = value.unsigned_abs();
+        NapiBigInt::from_words(
+            env,
+            value < 0,
+            &[magnitude as u64, (magnitude >> 64) as u64],
+        )
+    }
+
+    pub fn from_u128(env: &'env NapiEnv, value: u128) -> NapiResult<Self> {
+        NapiBigInt::from_words(
+            env,
+            false,
+            &[value as u64, (value >> 64) as u64],
+        )
+    }
+
+    pub fn from_words(
+        env: &'env NapiEnv,
+        negative: bool,
+        words: &[u64],
+    ) -> NapiResult<Self> {
+        let mut sys_value = ptr::null_mut();
+        env.handle_status_code(unsafe {
+            sys::napi_create_bigint_words(
+                env.as_sys_env(),
+                negative as _,
+                words.len(),
+                words.as_ptr(),
+                &mut sys_value,
+            )
+        })?;
+
+        Ok(Self {
+            value: sys_value,
+            env,
+        })
+    }
+
+    // The conversions below return the converted value along with a flag
+    // that tells whether it is lossless, i.e. whether the BigInt fits into
+    // the target type without being truncated.
+
+    pub fn to_i64(&self) -> NapiResult<(i64, bool)> {
+        let mut result = 0;
+        let mut lossless = false;
+
+        self.env.handle_status_code(unsafe {
+            sys::napi_get_value_bigint_int64(
+                self.env.as_sys_env(),
+                self.value,
+                &mut result,
+                &mut lossless,
+            )
+        })?;
+
+        Ok((result, lossless))
+    }
+
+    pub fn to_u64(&self) -> NapiResult<(u64, bool)> {
+        let mut result = 0;
+        let mut lossless = false;
+
+        self.env.handle_status_code(unsafe {
+            sys::napi_get_value_bigint_uint64(
+                self.env.as_sys_env(),
+                self.value,
+                &mut result,
+                &mut lossless,
+            )
+        })?;
+
+        Ok((result, lossless))
+    }
+
+    pub fn to_i128(&self) -> NapiResult<(i128, bool)> {
+        let (negative, words) = self.to_words()?;
+        let (magnitude, fits) = words_to_u128(&words);
+
+        if negative {
+            let lossless = fits && magnitude <= i128::MIN.unsigned_abs();
+            Ok(((magnitude as i128).wrapping_neg(), lossless))
+        } else {
+            let lossless = fits && magnitude <= i128::MAX as u128;
+            Ok((magnitude as i128, lossless))
+        }
+    }
+
+    pub fn to_u128(&self) -> NapiResult<(u128, bool)> {
+        let (negative, words) = self.to_words()?;
+        let (magnitude, fits) = words_to_u128(&words);
+
+        if negative {
+            Ok((magnitude.wrapping_neg(), fits && magnitude == 0))
+        } else {
+            Ok((magnitude, fits))
+        }
+    }
+
+    pub fn to_words(&self) -> NapiResult<(bool, Vec<u64>)> {
+        let mut word_count = 0;
+
+        self.env.handle_status_code(unsafe {
+            sys::napi_get_value_bigint_words(
+                self.env.as_sys_env(),
+                self.value,
+                ptr::null_mut(),
+                &mut word_count,
+                ptr::null_mut(),
+            )
+        })?;
+
+        let mut sign_bit = 0;
+        let mut words = vec![0; word_count];
+
+        self.env.handle_status_code(unsafe {
+            sys::napi_get_value_bigint_words(
+                self.env.as_sys_env(),
+                self.value,
+                &mut sign_bit,
+                &mut word_count,
+                words.as_mut_ptr(),
+            )
+        })?;
+
+        words.truncate(word_count);
+
+        Ok((sign_bit != 0, words))
+    }
+}
+
+// Returns the lowest 128 bits of a little-endian word array and whether the
+// higher words are all zero.
+#[cfg(feature = "napi6")]
+fn words_to_u128(words: &[u64]) -> (u128, bool) {
+    let low = words.first().cloned().unwrap_or(0) as u128;
+    let high = words.get(1).cloned().unwrap_or(0) as u128;
+    let fits = words.iter().skip(2).all(|&word| word == 0);
+
+    (low | high << 64, fits)
+}
+
+impl<'env> NapiValue<'env> for NapiBigInt<'env> {
+    fn as_sys_value(&self) -> sys::napi_value {
+        self.value
+    }
+
+    fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    fn from_sys_checked(
+        env: &'env NapiEnv,
+        value: sys::napi_value,
+    ) -> NapiResult<Self> {
+        if NapiAny::with_value(env, value).value_type()?
+            != NapiValueType::BigInt
+        {
+            let message = NapiString::from_str(env, "BigInt expected")?;
+            return Err(NapiError::type_error(env, &message));
+        }
+
+        Ok(Self { env, value })
+    }
+}
+
+impl<'env> NapiValueInternal<'env> for NapiBigInt<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
+    }
+}
@@ -1,20 +1,22 @@
+use std::ops::RangeBounds;
 use std::ptr;
-use std::slice;
 
 use env::NapiEnv;
 use result::{NapiError, NapiResult};
 use sys;
 
-use super::{AsNapiObject, NapiAny, NapiString, NapiValue};
+use super::bytes::resolve_range;
+use super::{AsNapiObject, NapiAny, NapiArrayBuffer, NapiBytes, NapiBytesMut,
+            NapiFunction, NapiObject, NapiString, NapiValue,
+            NapiValueInternal};
 
 #[derive(Debug)]
-pub struct NapiBuffer<'env, 'buf> {
+pub struct NapiBuffer<'env> {
     value: sys::napi_value,
-    data: &'buf mut [u8],
     env: &'env NapiEnv,
 }
 
-impl<'env, 'buf> NapiBuffer<'env, 'buf> {
+impl<'env> NapiBuffer<'env> {
     pub fn new(env: &'env NapiEnv, len: usize) -> NapiResult<Self> {
         let mut value = ptr::null_mut();
         let mut data = ptr::null_mut();
@@ -28,23 +30,157 @@ impl<'env, 'buf> NapiBuffer<'env, 'buf> {
             )
         })?;
 
-        Ok(Self {
-            value,
-            data: unsafe { slice::from_raw_parts_mut(data as *mut u8, len) },
-            env,
-        })
+        // Node.js does not initialize the memory of new buffers.
+        if !data.is_null() {
+            unsafe {
+                ptr::write_bytes(data as *mut u8, 0, len);
+            }
+        }
+
+        Ok(Self { value, env })
+    }
+
+    pub fn from_slice(env: &'env NapiEnv, data: &[u8]) -> NapiResult<Self> {
+        let mut value = ptr::null_mut();
+
+        env.handle_status(unsafe {
+            sys::napi_create_buffer_copy(
+                env.as_sys_env(),
+                data.len(),
+                data.as_ptr() as *const _,
+                ptr::null_mut(),
+                &mut value,
+            )
+        })?;
+
+        Ok(Self { value, env })
+    }
+
+    pub fn len(&self) -> NapiResult<usize> {
+        self.info().map(|(_, len)| len)
+    }
+
+    pub fn is_empty(&self) -> NapiResult<bool> {
+        self.len().map(|len| len == 0)
     }
 
-    pub fn len(&self) -> usize {
-        self.data.len()
+    pub fn bytes(&self) -> NapiResult<NapiBytes<'_>> {
+        let (data, len) = self.raw_data()?;
+        unsafe { NapiBytes::borrow(self.env, data, len) }
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+    pub fn bytes_mut(&mut self) -> NapiResult<NapiBytesMut<'_>> {
+        let (data, len) = self.raw_data()?;
+        unsafe { NapiBytesMut::borrow(self.env, data, len) }
+    }
+
+    // The slice is a Node.js `Buffer` over the same memory, whatever kind of
+    // ArrayBuffer view the buffer is.
+    pub fn slice<R>(&self, range: R) -> NapiResult<NapiBuffer<'env>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (start, end) = resolve_range(self.env, range, self.len()?)?;
+        let (arraybuffer, byte_offset) = self.arraybuffer()?;
+        let mut result = ptr::null_mut();
+
+        self.env.handle_status(unsafe {
+            sys::napi_create_typedarray(
+                self.env.as_sys_env(),
+                sys::napi_typedarray_type::napi_uint8_array,
+                end - start,
+                arraybuffer,
+                byte_offset + start,
+                &mut result,
+            )
+        })?;
+
+        // Buffers are the `Uint8Array`s with the prototype of buffers, which
+        // N-API 9 can only create over memory of their own.
+        let empty = NapiBuffer::new(self.env, 0)?;
+        let prototype = empty.as_napi_object().prototype()?;
+        let object = NapiFunction::from_sys_checked(
+            self.env,
+            NapiObject::global(self.env)?
+                .get_named_property("Object")?
+                .as_sys_value(),
+        )?;
+        let set_prototype = NapiFunction::from_sys_checked(
+            self.env,
+            object
+                .as_napi_object()
+                .get_named_property("setPrototypeOf")?
+                .as_sys_value(),
+        )?;
+        set_prototype.call(
+            &object,
+            &[NapiAny::with_value(self.env, result), prototype],
+        )?;
+
+        Ok(NapiBuffer::construct(self.env, result))
+    }
+
+    // The ArrayBuffer that the buffer is a view of, and the offset into it.
+    fn arraybuffer(&self) -> NapiResult<(sys::napi_value, usize)> {
+        let mut arraybuffer = ptr::null_mut();
+        let mut byte_offset = 0;
+
+        let status = if self.is_dataview()? {
+            unsafe {
+                sys::napi_get_dataview_info(
+                    self.env.as_sys_env(),
+                    self.value,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    &mut arraybuffer,
+                    &mut byte_offset,
+                )
+            }
+        } else {
+            unsafe {
+                sys::napi_get_typedarray_info(
+                    self.env.as_sys_env(),
+                    self.value,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    &mut arraybuffer,
+                    &mut byte_offset,
+                )
+            }
+        };
+        self.env.handle_status(status)?;
+
+        Ok((arraybuffer, byte_offset))
+    }
+
+    fn raw_data(&self) -> NapiResult<(*mut u8, usize)> {
+        let (arraybuffer, _) = self.arraybuffer()?;
+        NapiArrayBuffer::construct(self.env, arraybuffer).ensure_attached()?;
+
+        self.info()
+    }
+
+    // Node.js accepts any ArrayBuffer view as a buffer, and reports the
+    // length of all of them in bytes here.
+    fn info(&self) -> NapiResult<(*mut u8, usize)> {
+        let mut data = ptr::null_mut();
+        let mut len = 0;
+
+        self.env.handle_status(unsafe {
+            sys::napi_get_buffer_info(
+                self.env.as_sys_env(),
+                self.value,
+                &mut data,
+                &mut len,
+            )
+        })?;
+
+        Ok((data as *mut u8, len))
     }
 }
 
-impl<'env, 'buf> NapiValue<'env> for NapiBuffer<'env, 'buf> {
+impl<'env> NapiValue<'env> for NapiBuffer<'env> {
     fn as_sys_value(&self) -> sys::napi_value {
         self.value
     }
@@ -62,36 +198,14 @@ impl<'env, 'buf> NapiValue<'env> for NapiBuffer<'env, 'buf> {
             return Err(NapiError::type_error(env, &message));
         }
 
-        let mut data = ptr::null_mut();
-        let mut len = 0;
-
-        env.handle_status(unsafe {
-            sys::napi_get_buffer_info(
-                env.as_sys_env(),
-                value,
-                &mut data,
-                &mut len,
-            )
-        })?;
-
-        Ok(Self {
-            env,
-            value,
-            data: unsafe { slice::from_raw_parts_mut(data as *mut u8, len) },
-        })
+        Ok(Self { env, value })
     }
 }
 
-impl<'env, 'buf> AsNapiObject<'env> for NapiBuffer<'env, 'buf> {}
-
-impl<'env, 'buf> AsRef<[u8]> for NapiBuffer<'env, 'buf> {
-    fn as_ref(&self) -> &[u8] {
-        self.data
+impl<'env> NapiValueInternal<'env> for NapiBuffer<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
     }
 }
 
-impl<'env, 'buf> AsMut<[u8]> for NapiBuffer<'env, 'buf> {
-    fn as_mut(&mut self) -> &mut [u8] {
-        self.data
-    }
-}
+impl<'env> AsNapiObject<'env> for NapiBuffer<'env> {}
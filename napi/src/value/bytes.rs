@@ -0,0 +1,289 @@
+use std::cell::RefCell;
+use std::ops::{Bound, Deref, DerefMut, RangeBounds};
+use std::rc::Rc;
+use std::slice;
+
+use env::NapiEnv;
+use result::{NapiError, NapiResult};
+
+use super::NapiString;
+
+// The memory behind buffers and array buffers is owned by the JavaScript VM,
+// and any number of wrappers may point to the same bytes, so the borrow rules
+// are enforced at runtime, similarly to `RefCell`.  Every live `NapiBytes` or
+// `NapiBytesMut` registers the address range it covers in this list.
+//
+// The registry only sees native code, though.  JavaScript can still write to
+// the memory, or detach or transfer the ArrayBuffer and free it, so no
+// JavaScript may run while a borrow is held: calling functions, getting or
+// setting properties that may be accessors, or anything else that can call
+// back into scripts.  Accessors that borrow fail instead if the ArrayBuffer
+// is already detached.
+thread_local! {
+    static BORROWS: RefCell<Vec<Borrow>> =
+        const { RefCell::new(Vec::new()) };
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Borrow {
+    start: usize,
+    end: usize,
+    mutable: bool,
+}
+
+impl Borrow {
+    fn overlaps(&self, other: &Borrow) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    fn conflicts(&self, other: &Borrow) -> bool {
+        (self.mutable || other.mutable) && self.overlaps(other)
+    }
+}
+
+#[derive(Debug)]
+struct BorrowGuard {
+    borrow: Borrow,
+}
+
+impl BorrowGuard {
+    fn acquire(
+        env: &NapiEnv,
+        data: *const u8,
+        len: usize,
+        mutable: bool,
+    ) -> NapiResult<Self> {
+        let end = match (data as usize).checked_add(len) {
+            Some(end) => end,
+            None => {
+                let message =
+                    NapiString::from_str(env, "Buffer data out of bounds")?;
+                return Err(NapiError::range_error(env, &message));
+            }
+        };
+        let borrow = Borrow {
+            start: data as usize,
+            end,
+            mutable,
+        };
+
+        let acquired = BORROWS.with(|borrows| {
+            let mut borrows = borrows.borrow_mut();
+            if borrows.iter().any(|other| borrow.conflicts(other)) {
+                return false;
+            }
+            borrows.push(borrow);
+            true
+        });
+
+        if !acquired {
+            let message = NapiString::from_str(
+                env,
+                if mutable {
+                    "Buffer data is already borrowed"
+                } else {
+                    "Buffer data is already mutably borrowed"
+                },
+            )?;
+            return Err(NapiError::error(env, &message));
+        }
+
+        Ok(Self { borrow })
+    }
+}
+
+// Resolves `range` against a buffer of `len` bytes, failing with a
+// `RangeError` if it is out of bounds.
+pub(crate) fn resolve_range<R>(
+    env: &NapiEnv,
+    range: R,
+    len: usize,
+) -> NapiResult<(usize, usize)>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        Bound::Excluded(&start) => start.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => Some(len),
+    };
+
+    match (start, end) {
+        (Some(start), Some(end)) if start <= end && end <= len => {
+            Ok((start, end))
+        }
+        _ => {
+            let message = NapiString::from_str(env, "Range out of bounds")?;
+            Err(NapiError::range_error(env, &message))
+        }
+    }
+}
+
+// Fails if any part of the given memory is currently borrowed.
+#[cfg(feature = "napi7")]
+pub(crate) fn ensure_unborrowed(
+    env: &NapiEnv,
+    data: *const u8,
+    len: usize,
+) -> NapiResult<()> {
+    if data.is_null() || len == 0 {
+        return Ok(());
+    }
+
+    BorrowGuard::acquire(env, data, len, true).map(|_| ())
+}
+
+impl Drop for BorrowGuard {
+    fn drop(&mut self) {
+        let borrow = self.borrow;
+        BORROWS.with(|borrows| {
+            let mut borrows = borrows.borrow_mut();
+            if let Some(index) = borrows.iter().position(|b| *b == borrow) {
+                borrows.swap_remove(index);
+            }
+        });
+    }
+}
+
+// The bytes of a buffer, borrowed from JavaScript.  Drop it before running
+// any JavaScript, which could otherwise change or free the memory.
+#[derive(Debug)]
+pub struct NapiBytes<'a> {
+    data: &'a [u8],
+    guard: Option<Rc<BorrowGuard>>,
+}
+
+impl<'a> NapiBytes<'a> {
+    // The caller must guarantee that `data` points to `len` bytes that stay
+    // valid for `'a`, or is null when `len` is zero.
+    pub(crate) unsafe fn borrow(
+        env: &NapiEnv,
+        data: *const u8,
+        len: usize,
+    ) -> NapiResult<Self> {
+        if data.is_null() || len == 0 {
+            return Ok(Self {
+                data: &[],
+                guard: None,
+            });
+        }
+
+        let guard = BorrowGuard::acquire(env, data, len, false)?;
+
+        Ok(Self {
+            data: slice::from_raw_parts(data, len),
+            guard: Some(Rc::new(guard)),
+        })
+    }
+
+    pub fn split_at(self, mid: usize) -> (NapiBytes<'a>, NapiBytes<'a>) {
+        let (left, right) = self.data.split_at(mid);
+
+        (
+            NapiBytes {
+                data: left,
+                guard: self.guard.clone(),
+            },
+            NapiBytes {
+                data: right,
+                guard: self.guard,
+            },
+        )
+    }
+}
+
+impl<'a> Deref for NapiBytes<'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.data
+    }
+}
+
+impl<'a> AsRef<[u8]> for NapiBytes<'a> {
+    fn as_ref(&self) -> &[u8] {
+        self.data
+    }
+}
+
+// The bytes of a buffer, borrowed mutably from JavaScript.  Drop it before
+// running any JavaScript, which could otherwise change or free the memory.
+#[derive(Debug)]
+pub struct NapiBytesMut<'a> {
+    data: &'a mut [u8],
+    guard: Option<Rc<BorrowGuard>>,
+}
+
+impl<'a> NapiBytesMut<'a> {
+    // The caller must guarantee that `data` points to `len` bytes that stay
+    // valid for `'a`, or is null when `len` is zero.
+    pub(crate) unsafe fn borrow(
+        env: &NapiEnv,
+        data: *mut u8,
+        len: usize,
+    ) -> NapiResult<Self> {
+        if data.is_null() || len == 0 {
+            return Ok(Self {
+                data: &mut [],
+                guard: None,
+            });
+        }
+
+        let guard = BorrowGuard::acquire(env, data, len, true)?;
+
+        Ok(Self {
+            data: slice::from_raw_parts_mut(data, len),
+            guard: Some(Rc::new(guard)),
+        })
+    }
+
+    pub fn split_at_mut(
+        self,
+        mid: usize,
+    ) -> (NapiBytesMut<'a>, NapiBytesMut<'a>) {
+        let (left, right) = self.data.split_at_mut(mid);
+
+        (
+            NapiBytesMut {
+                data: left,
+                guard: self.guard.clone(),
+            },
+            NapiBytesMut {
+                data: right,
+                guard: self.guard,
+            },
+        )
+    }
+}
+
+impl<'a> Deref for NapiBytesMut<'a> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.data
+    }
+}
+
+impl<'a> DerefMut for NapiBytesMut<'a> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.data
+    }
+}
+
+impl<'a> AsRef<[u8]> for NapiBytesMut<'a> {
+    fn as_ref(&self) -> &[u8] {
+        self.data
+    }
+}
+
+impl<'a> AsMut<[u8]> for NapiBytesMut<'a> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.data
+    }
+}
@@ -0,0 +1,120 @@
+use std::ptr;
+
+use env::NapiEnv;
+use result::{NapiError, NapiResult};
+use sys;
+
+use super::{AsNapiObject, NapiAny, NapiArrayBuffer, NapiBytes, NapiBytesMut,
+            NapiString, NapiValue, NapiValueInternal};
+
+#[derive(Debug)]
+pub struct NapiDataView<'env> {
+    value: sys::napi_value,
+    env: &'env NapiEnv,
+}
+
+impl<'env> NapiDataView<'env> {
+    pub fn new(
+        env: &'env NapiEnv,
+        arraybuffer: &NapiArrayBuffer<'env>,
+        byte_offset: usize,
+        byte_len: usize,
+    ) -> NapiResult<Self> {
+        let mut value = ptr::null_mut();
+        env.handle_status(unsafe {
+            sys::napi_create_dataview(
+                env.as_sys_env(),
+                byte_len,
+                arraybuffer.as_sys_value(),
+                byte_offset,
+                &mut value,
+            )
+        })?;
+
+        Ok(Self { value, env })
+    }
+
+    pub fn byte_len(&self) -> NapiResult<usize> {
+        self.info().map(|(_, byte_len, _, _)| byte_len)
+    }
+
+    pub fn byte_offset(&self) -> NapiResult<usize> {
+        self.info().map(|(_, _, _, byte_offset)| byte_offset)
+    }
+
+    pub fn arraybuffer(&self) -> NapiResult<NapiArrayBuffer<'env>> {
+        self.info()
+            .map(|(_, _, arraybuffer, _)| {
+                NapiArrayBuffer::construct(self.env, arraybuffer)
+            })
+    }
+
+    pub fn bytes(&self) -> NapiResult<NapiBytes<'_>> {
+        let (data, len) = self.raw_data()?;
+        unsafe { NapiBytes::borrow(self.env, data, len) }
+    }
+
+    pub fn bytes_mut(&mut self) -> NapiResult<NapiBytesMut<'_>> {
+        let (data, len) = self.raw_data()?;
+        unsafe { NapiBytesMut::borrow(self.env, data, len) }
+    }
+
+    fn raw_data(&self) -> NapiResult<(*mut u8, usize)> {
+        let (data, byte_len, arraybuffer, _) = self.info()?;
+        NapiArrayBuffer::construct(self.env, arraybuffer).ensure_attached()?;
+
+        Ok((data, byte_len))
+    }
+
+    fn info(
+        &self,
+    ) -> NapiResult<(*mut u8, usize, sys::napi_value, usize)> {
+        let mut data = ptr::null_mut();
+        let mut byte_len = 0;
+        let mut arraybuffer = ptr::null_mut();
+        let mut byte_offset = 0;
+
+        self.env.handle_status(unsafe {
+            sys::napi_get_dataview_info(
+                self.env.as_sys_env(),
+                self.value,
+                &mut byte_len,
+                &mut data,
+                &mut arraybuffer,
+                &mut byte_offset,
+            )
+        })?;
+
+        Ok((data as *mut u8, byte_len, arraybuffer, byte_offset))
+    }
+}
+
+impl<'env> NapiValue<'env> for NapiDataView<'env> {
+    fn as_sys_value(&self) -> sys::napi_value {
+        self.value
+    }
+
+    fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    fn from_sys_checked(
+        env: &'env NapiEnv,
+        value: sys::napi_value,
+    ) -> NapiResult<Self> {
+        if !NapiAny::with_value(env, value).is_dataview()? {
+            let message = NapiString::from_str(env, "DataView expected")?;
+            return Err(NapiError::type_error(env, &message));
+        }
+
+        Ok(Self { env, value })
+    }
+}
+
+impl<'env> NapiValueInternal<'env> for NapiDataView<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
+    }
+}
+
+impl<'env> AsNapiObject<'env> for NapiDataView<'env> {}
@@ -0,0 +1,165 @@
+use std::ptr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, TimeZone, Utc};
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+
+use env::NapiEnv;
+use result::{NapiError, NapiErrorKind, NapiResult};
+use sys;
+
+use super::{AsNapiObject, NapiAny, NapiString, NapiValue, NapiValueInternal};
+
+#[derive(Clone, Copy, Debug)]
+pub struct NapiDate<'env> {
+    value: sys::napi_value,
+    env: &'env NapiEnv,
+}
+
+impl<'env> NapiDate<'env> {
+    pub fn from_millis(env: &'env NapiEnv, millis: f64) -> NapiResult<Self> {
+        let mut value = ptr::null_mut();
+        env.handle_status_code(unsafe {
+            sys::napi_create_date(env.as_sys_env(), millis, &mut value)
+        })?;
+
+        Ok(Self { value, env })
+    }
+
+    pub fn from_system_time(
+        env: &'env NapiEnv,
+        time: SystemTime,
+    ) -> NapiResult<Self> {
+        let millis = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration_to_millis(duration),
+            Err(error) => -duration_to_millis(error.duration()),
+        };
+
+        NapiDate::from_millis(env, millis)
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn from_chrono<Tz>(
+        env: &'env NapiEnv,
+        time: &DateTime<Tz>,
+    ) -> NapiResult<Self>
+    where
+        Tz: TimeZone,
+    {
+        NapiDate::from_millis(env, time.timestamp_millis() as f64)
+    }
+
+    #[cfg(feature = "time")]
+    pub fn from_time(
+        env: &'env NapiEnv,
+        time: OffsetDateTime,
+    ) -> NapiResult<Self> {
+        let millis = time.unix_timestamp_nanos() as f64 / 1_000_000.0;
+        NapiDate::from_millis(env, millis.floor())
+    }
+
+    pub fn to_millis(&self) -> NapiResult<f64> {
+        let mut result = 0.0;
+
+        self.env.handle_status_code(unsafe {
+            sys::napi_get_date_value(
+                self.env.as_sys_env(),
+                self.value,
+                &mut result,
+            )
+        })?;
+
+        if result.is_nan() {
+            return Err(NapiError {
+                kind: NapiErrorKind::InvalidDate,
+                message: None,
+                exception: None,
+                path: Vec::new(),
+            });
+        }
+
+        Ok(result)
+    }
+
+    pub fn to_system_time(&self) -> NapiResult<SystemTime> {
+        let millis = self.to_millis()?;
+        let duration = Duration::from_millis(millis.abs() as u64);
+
+        let time = if millis < 0.0 {
+            UNIX_EPOCH.checked_sub(duration)
+        } else {
+            UNIX_EPOCH.checked_add(duration)
+        };
+
+        time.ok_or_else(|| NapiError {
+            kind: NapiErrorKind::InvalidDate,
+            message: Some(format!("{} is out of range", millis)),
+            exception: None,
+            path: Vec::new(),
+        })
+    }
+
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono(&self) -> NapiResult<DateTime<Utc>> {
+        let millis = self.to_millis()?;
+
+        Utc.timestamp_millis_opt(millis as i64)
+            .single()
+            .ok_or_else(|| NapiError {
+                kind: NapiErrorKind::InvalidDate,
+                message: Some(format!("{} is out of range", millis)),
+                exception: None,
+                path: Vec::new(),
+            })
+    }
+
+    #[cfg(feature = "time")]
+    pub fn to_time(&self) -> NapiResult<OffsetDateTime> {
+        let millis = self.to_millis()?;
+
+        OffsetDateTime::from_unix_timestamp_nanos(millis as i128 * 1_000_000)
+            .map_err(|error| NapiError {
+                kind: NapiErrorKind::InvalidDate,
+                message: Some(error.to_string()),
+                exception: None,
+                path: Vec::new(),
+            })
+    }
+}
+
+fn duration_to_millis(duration: Duration) -> f64 {
+    duration.as_secs() as f64 * 1000.0
+        + f64::from(duration.subsec_millis())
+}
+
+impl<'env> NapiValue<'env> for NapiDate<'env> {
+    fn as_sys_value(&self) -> sys::napi_value {
+        self.value
+    }
+
+    fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    fn from_sys_checked(
+        env: &'env NapiEnv,
+        value: sys::napi_value,
+    ) -> NapiResult<Self> {
+        if !NapiAny::with_value(env, value).is_date()? {
+            let message = NapiString::from_str(env, "Date expected")?;
+            return Err(NapiError::type_error(env, &message));
+        }
+
+        Ok(Self { env, value })
+    }
+}
+
+impl<'env> NapiValueInternal<'env> for NapiDate<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
+    }
+}
+
+impl<'env> AsNapiObject<'env> for NapiDate<'env> {}
@@ -0,0 +1,59 @@
+use env::NapiEnv;
+use result::{NapiError, NapiErrorKind, NapiResult};
+use sys;
+
+use super::{AsNapiObject, NapiAny, NapiString, NapiValue, NapiValueInternal};
+
+#[derive(Clone, Copy, Debug)]
+pub struct NapiErrorObject<'env> {
+    value: sys::napi_value,
+    env: &'env NapiEnv,
+}
+
+impl<'env> NapiErrorObject<'env> {
+    pub fn message(&self) -> NapiResult<String> {
+        self.as_napi_object()
+            .get_named_property("message")?
+            .to_napi_string()?
+            .to_string()
+    }
+
+    pub fn into_error(self) -> NapiError {
+        NapiError {
+            kind: NapiErrorKind::ApplicationError,
+            message: None,
+            exception: Some(self.value),
+            path: Vec::new(),
+        }
+    }
+}
+
+impl<'env> NapiValue<'env> for NapiErrorObject<'env> {
+    fn as_sys_value(&self) -> sys::napi_value {
+        self.value
+    }
+
+    fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    fn from_sys_checked(
+        env: &'env NapiEnv,
+        value: sys::napi_value,
+    ) -> NapiResult<Self> {
+        if !NapiAny::with_value(env, value).is_error()? {
+            let message = NapiString::from_str(env, "Error expected")?;
+            return Err(NapiError::type_error(env, &message));
+        }
+
+        Ok(Self { env, value })
+    }
+}
+
+impl<'env> NapiValueInternal<'env> for NapiErrorObject<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
+    }
+}
+
+impl<'env> AsNapiObject<'env> for NapiErrorObject<'env> {}
@@ -0,0 +1,60 @@
+use std::os::raw::c_void;
+use std::ptr;
+
+use env::NapiEnv;
+use result::{NapiError, NapiResult};
+use sys;
+
+use super::{NapiAny, NapiString, NapiValue, NapiValueInternal, NapiValueType};
+
+#[derive(Clone, Copy, Debug)]
+pub struct NapiExternal<'env> {
+    value: sys::napi_value,
+    env: &'env NapiEnv,
+}
+
+impl<'env> NapiExternal<'env> {
+    pub fn data(&self) -> NapiResult<*mut c_void> {
+        let mut result = ptr::null_mut();
+
+        self.env.handle_status(unsafe {
+            sys::napi_get_value_external(
+                self.env.as_sys_env(),
+                self.value,
+                &mut result,
+            )
+        })?;
+
+        Ok(result)
+    }
+}
+
+impl<'env> NapiValue<'env> for NapiExternal<'env> {
+    fn as_sys_value(&self) -> sys::napi_value {
+        self.value
+    }
+
+    fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    fn from_sys_checked(
+        env: &'env NapiEnv,
+        value: sys::napi_value,
+    ) -> NapiResult<Self> {
+        if NapiAny::with_value(env, value).value_type()?
+            != NapiValueType::External
+        {
+            let message = NapiString::from_str(env, "External expected")?;
+            return Err(NapiError::type_error(env, &message));
+        }
+
+        Ok(Self { env, value })
+    }
+}
+
+impl<'env> NapiValueInternal<'env> for NapiExternal<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
+    }
+}
@@ -0,0 +1,97 @@
+use std::ptr;
+
+use callback::NapiCallback;
+use env::NapiEnv;
+use result::{NapiError, NapiResult};
+use sys;
+
+use super::{AsNapiObject, NapiAny, NapiString, NapiValue, NapiValueInternal,
+            NapiValueType};
+
+#[derive(Clone, Copy, Debug)]
+pub struct NapiFunction<'env> {
+    value: sys::napi_value,
+    env: &'env NapiEnv,
+}
+
+impl<'env> NapiFunction<'env> {
+    pub fn new(
+        env: &'env NapiEnv,
+        name: &str,
+        callback: NapiCallback,
+    ) -> NapiResult<Self> {
+        let mut value = ptr::null_mut();
+
+        env.handle_status(unsafe {
+            sys::napi_create_function(
+                env.as_sys_env(),
+                name.as_ptr() as *const _,
+                name.len(),
+                Some(callback),
+                ptr::null_mut(),
+                &mut value,
+            )
+        })?;
+
+        Ok(Self { value, env })
+    }
+
+    pub fn call<T>(
+        &self,
+        this: &T,
+        args: &[NapiAny<'env>],
+    ) -> NapiResult<NapiAny<'env>>
+    where
+        T: NapiValue<'env>,
+    {
+        let argv = args.iter()
+            .map(|arg| arg.as_sys_value())
+            .collect::<Vec<_>>();
+        let mut result = ptr::null_mut();
+
+        self.env.handle_status(unsafe {
+            sys::napi_call_function(
+                self.env.as_sys_env(),
+                this.as_sys_value(),
+                self.value,
+                argv.len(),
+                argv.as_ptr(),
+                &mut result,
+            )
+        })?;
+
+        Ok(NapiAny::with_value(self.env, result))
+    }
+}
+
+impl<'env> NapiValue<'env> for NapiFunction<'env> {
+    fn as_sys_value(&self) -> sys::napi_value {
+        self.value
+    }
+
+    fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    fn from_sys_checked(
+        env: &'env NapiEnv,
+        value: sys::napi_value,
+    ) -> NapiResult<Self> {
+        if NapiAny::with_value(env, value).value_type()?
+            != NapiValueType::Function
+        {
+            let message = NapiString::from_str(env, "Function expected")?;
+            return Err(NapiError::type_error(env, &message));
+        }
+
+        Ok(Self { env, value })
+    }
+}
+
+impl<'env> NapiValueInternal<'env> for NapiFunction<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
+    }
+}
+
+impl<'env> AsNapiObject<'env> for NapiFunction<'env> {}
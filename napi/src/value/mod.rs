@@ -7,23 +7,45 @@ use sys;
 mod any;
 mod array;
 mod array_buffer;
+mod bigint;
 mod boolean;
 mod buffer;
+mod bytes;
+mod data_view;
+#[cfg(feature = "napi5")]
+mod date;
+mod error;
+mod external;
+mod function;
 mod null;
 mod number;
 mod object;
+mod promise;
 mod string;
+mod symbol;
+mod typed_array;
 mod undefined;
 
-pub use self::any::NapiAny;
+pub use self::any::{NapiAny, NapiDowncast};
 pub use self::array::NapiArray;
 pub use self::array_buffer::NapiArrayBuffer;
+pub use self::bigint::NapiBigInt;
 pub use self::boolean::NapiBoolean;
 pub use self::buffer::NapiBuffer;
+pub use self::bytes::{NapiBytes, NapiBytesMut};
+pub use self::data_view::NapiDataView;
+#[cfg(feature = "napi5")]
+pub use self::date::NapiDate;
+pub use self::error::NapiErrorObject;
+pub use self::external::NapiExternal;
+pub use self::function::NapiFunction;
 pub use self::null::NapiNull;
 pub use self::number::NapiNumber;
 pub use self::object::NapiObject;
+pub use self::promise::NapiPromise;
 pub use self::string::NapiString;
+pub use self::symbol::NapiSymbol;
+pub use self::typed_array::NapiTypedArray;
 pub use self::undefined::NapiUndefined;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -37,6 +59,39 @@ pub enum NapiValueType {
     Object,
     Function,
     External,
+    BigInt,
+    // A type added by a newer runtime.
+    Unknown(sys::napi_valuetype_code),
+}
+
+impl NapiValueType {
+    fn from_code(code: sys::napi_valuetype_code) -> Self {
+        use sys::napi_valuetype::*;
+
+        const UNDEFINED: sys::napi_valuetype_code = napi_undefined as _;
+        const NULL: sys::napi_valuetype_code = napi_null as _;
+        const BOOLEAN: sys::napi_valuetype_code = napi_boolean as _;
+        const NUMBER: sys::napi_valuetype_code = napi_number as _;
+        const STRING: sys::napi_valuetype_code = napi_string as _;
+        const SYMBOL: sys::napi_valuetype_code = napi_symbol as _;
+        const OBJECT: sys::napi_valuetype_code = napi_object as _;
+        const FUNCTION: sys::napi_valuetype_code = napi_function as _;
+        const EXTERNAL: sys::napi_valuetype_code = napi_external as _;
+
+        match code {
+            UNDEFINED => NapiValueType::Undefined,
+            NULL => NapiValueType::Null,
+            BOOLEAN => NapiValueType::Boolean,
+            NUMBER => NapiValueType::Number,
+            STRING => NapiValueType::String,
+            SYMBOL => NapiValueType::Symbol,
+            OBJECT => NapiValueType::Object,
+            FUNCTION => NapiValueType::Function,
+            EXTERNAL => NapiValueType::External,
+            sys::napi_bigint => NapiValueType::BigInt,
+            _ => NapiValueType::Unknown(code),
+        }
+    }
 }
 
 pub trait NapiValue<'env>: Sized {
@@ -69,23 +124,17 @@ pub trait NapiValue<'env>: Sized {
 
     fn value_type(&self) -> NapiResult<NapiValueType> {
         let env = self.env();
-        let mut result = sys::napi_valuetype::napi_undefined;
+        let mut result = 0;
 
         env.handle_status(unsafe {
-            sys::napi_typeof(env.as_sys_env(), self.as_sys_value(), &mut result)
+            sys::napi_typeof_code(
+                env.as_sys_env(),
+                self.as_sys_value(),
+                &mut result,
+            )
         })?;
 
-        Ok(match result {
-            sys::napi_valuetype::napi_undefined => NapiValueType::Undefined,
-            sys::napi_valuetype::napi_null => NapiValueType::Null,
-            sys::napi_valuetype::napi_boolean => NapiValueType::Boolean,
-            sys::napi_valuetype::napi_number => NapiValueType::Number,
-            sys::napi_valuetype::napi_string => NapiValueType::String,
-            sys::napi_valuetype::napi_symbol => NapiValueType::Symbol,
-            sys::napi_valuetype::napi_object => NapiValueType::Object,
-            sys::napi_valuetype::napi_function => NapiValueType::Function,
-            sys::napi_valuetype::napi_external => NapiValueType::External,
-        })
+        Ok(NapiValueType::from_code(result))
     }
 
     fn instanceof(&self, constructor: &NapiObject) -> NapiResult<bool> {
@@ -120,6 +169,26 @@ pub trait NapiValue<'env>: Sized {
         check_type(self, sys::napi_is_error)
     }
 
+    #[cfg(feature = "napi5")]
+    fn is_date(&self) -> NapiResult<bool> {
+        let env = self.env();
+        let mut result = false;
+
+        env.handle_status_code(unsafe {
+            sys::napi_is_date(
+                env.as_sys_env(),
+                self.as_sys_value(),
+                &mut result,
+            )
+        })?;
+
+        Ok(result)
+    }
+
+    fn is_promise(&self) -> NapiResult<bool> {
+        check_type(self, sys::napi_is_promise)
+    }
+
     fn is_typedarray(&self) -> NapiResult<bool> {
         check_type(self, sys::napi_is_typedarray)
     }
@@ -128,6 +197,7 @@ pub trait NapiValue<'env>: Sized {
         check_type(self, sys::napi_is_dataview)
     }
 
+    #[allow(clippy::needless_maybe_sized)]
     fn strict_equals<T>(&self, other: &T) -> NapiResult<bool>
     where
         T: NapiValue<'env> + ?Sized,
@@ -158,6 +228,7 @@ trait NapiValueInternal<'env>: NapiValue<'env> {
     fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self;
 }
 
+#[allow(clippy::needless_maybe_sized)]
 fn coerce<'env, T, U>(
     value: &T,
     napi_fn: unsafe extern "C" fn(
@@ -180,6 +251,7 @@ where
     Ok(U::construct(env, coerced_value))
 }
 
+#[allow(clippy::needless_maybe_sized)]
 fn check_type<'env, T>(
     value: &T,
     napi_fn: unsafe extern "C" fn(sys::napi_env, sys::napi_value, *mut bool)
@@ -197,3 +269,14 @@ where
 
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_unknown_value_types() {
+        assert_eq!(NapiValueType::from_code(9), NapiValueType::BigInt);
+        assert_eq!(NapiValueType::from_code(10), NapiValueType::Unknown(10));
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "napi5")]
+use std::os::raw::c_void;
 use std::ptr;
 
 use env::NapiEnv;
@@ -23,7 +25,16 @@ impl<'env> NapiObject<'env> {
         Ok(Self { value, env })
     }
 
-    pub fn prototype(&self) -> NapiResult<NapiAny> {
+    pub fn global(env: &'env NapiEnv) -> NapiResult<Self> {
+        let mut value = ptr::null_mut();
+        env.handle_status(unsafe {
+            sys::napi_get_global(env.as_sys_env(), &mut value)
+        })?;
+
+        Ok(Self { value, env })
+    }
+
+    pub fn prototype(&self) -> NapiResult<NapiAny<'env>> {
         let mut result = ptr::null_mut();
 
         self.env.handle_status(unsafe {
@@ -219,6 +230,78 @@ impl<'env> NapiObject<'env> {
 
         Ok(result)
     }
+
+    // Calls `finalize` once the object has been garbage collected.
+    #[cfg(feature = "napi5")]
+    pub fn add_finalizer<F>(&self, finalize: F) -> NapiResult<()>
+    where
+        F: FnOnce() + 'static,
+    {
+        let data = Box::into_raw(Box::new(finalize));
+
+        self.env
+            .handle_status_code(unsafe {
+                sys::napi_add_finalizer(
+                    self.env.as_sys_env(),
+                    self.value,
+                    data as *mut c_void,
+                    Some(call_finalizer::<F>),
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                )
+            })
+            .inspect_err(|_| {
+                drop(unsafe { Box::from_raw(data) });
+            })
+    }
+
+    // Marks the object with `tag`, e.g. to later check that it wraps a
+    // particular native type.  An object can only be tagged once.
+    #[cfg(feature = "napi8")]
+    pub fn type_tag(&self, tag: u128) -> NapiResult<()> {
+        let tag = sys_type_tag(tag);
+
+        self.env.handle_status_code(unsafe {
+            sys::napi_type_tag_object(self.env.as_sys_env(), self.value, &tag)
+        })
+    }
+
+    #[cfg(feature = "napi8")]
+    pub fn has_type_tag(&self, tag: u128) -> NapiResult<bool> {
+        let tag = sys_type_tag(tag);
+        let mut result = false;
+
+        self.env.handle_status_code(unsafe {
+            sys::napi_check_object_type_tag(
+                self.env.as_sys_env(),
+                self.value,
+                &tag,
+                &mut result,
+            )
+        })?;
+
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "napi5")]
+unsafe extern "C" fn call_finalizer<F>(
+    _: sys::napi_env,
+    data: *mut c_void,
+    _: *mut c_void,
+) where
+    F: FnOnce(),
+{
+    let finalize = Box::from_raw(data as *mut F);
+    finalize();
+}
+
+#[cfg(feature = "napi8")]
+fn sys_type_tag(tag: u128) -> sys::napi_type_tag {
+    sys::napi_type_tag {
+        lower: tag as u64,
+        upper: (tag >> 64) as u64,
+    }
 }
 
 impl<'env> NapiValue<'env> for NapiObject<'env> {
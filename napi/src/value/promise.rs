@@ -0,0 +1,41 @@
+use env::NapiEnv;
+use result::{NapiError, NapiResult};
+use sys;
+
+use super::{AsNapiObject, NapiAny, NapiString, NapiValue, NapiValueInternal};
+
+#[derive(Clone, Copy, Debug)]
+pub struct NapiPromise<'env> {
+    value: sys::napi_value,
+    env: &'env NapiEnv,
+}
+
+impl<'env> NapiValue<'env> for NapiPromise<'env> {
+    fn as_sys_value(&self) -> sys::napi_value {
+        self.value
+    }
+
+    fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    fn from_sys_checked(
+        env: &'env NapiEnv,
+        value: sys::napi_value,
+    ) -> NapiResult<Self> {
+        if !NapiAny::with_value(env, value).is_promise()? {
+            let message = NapiString::from_str(env, "Promise expected")?;
+            return Err(NapiError::type_error(env, &message));
+        }
+
+        Ok(Self { env, value })
+    }
+}
+
+impl<'env> NapiValueInternal<'env> for NapiPromise<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
+    }
+}
+
+impl<'env> AsNapiObject<'env> for NapiPromise<'env> {}
@@ -13,6 +13,7 @@ pub struct NapiString<'env> {
 }
 
 impl<'env> NapiString<'env> {
+    #[allow(clippy::needless_as_bytes)]
     pub fn from_str(env: &'env NapiEnv, value: &str) -> NapiResult<Self> {
         let mut sys_value = ptr::null_mut();
         env.handle_status(unsafe {
@@ -64,6 +65,7 @@ impl<'env> NapiString<'env> {
         })
     }
 
+    #[allow(clippy::wrong_self_convention)]
     fn to_vec<T, U>(
         &self,
         get_value: unsafe extern "C" fn(
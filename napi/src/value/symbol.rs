@@ -0,0 +1,128 @@
+use std::ptr;
+
+use env::NapiEnv;
+use result::{NapiError, NapiResult};
+use sys;
+
+use super::{AsNapiObject, NapiAny, NapiObject, NapiString, NapiValue,
+            NapiValueInternal, NapiValueType};
+
+#[derive(Clone, Copy, Debug)]
+pub struct NapiSymbol<'env> {
+    value: sys::napi_value,
+    env: &'env NapiEnv,
+}
+
+impl<'env> NapiSymbol<'env> {
+    pub fn new(
+        env: &'env NapiEnv,
+        description: Option<&str>,
+    ) -> NapiResult<Self> {
+        let description = match description {
+            Some(description) => {
+                NapiString::from_str(env, description)?.as_sys_value()
+            }
+            None => ptr::null_mut(),
+        };
+
+        let mut value = ptr::null_mut();
+        env.handle_status(unsafe {
+            sys::napi_create_symbol(env.as_sys_env(), description, &mut value)
+        })?;
+
+        Ok(Self { value, env })
+    }
+
+    #[cfg(feature = "napi9")]
+    pub fn for_key(env: &'env NapiEnv, key: &str) -> NapiResult<Self> {
+        let mut value = ptr::null_mut();
+        env.handle_status_code(unsafe {
+            sys::node_api_symbol_for(
+                env.as_sys_env(),
+                key.as_ptr() as *const i8,
+                key.len(),
+                &mut value,
+            )
+        })?;
+
+        Ok(Self { value, env })
+    }
+
+    pub fn iterator(env: &'env NapiEnv) -> NapiResult<Self> {
+        NapiSymbol::well_known(env, "iterator")
+    }
+
+    pub fn async_iterator(env: &'env NapiEnv) -> NapiResult<Self> {
+        NapiSymbol::well_known(env, "asyncIterator")
+    }
+
+    pub fn has_instance(env: &'env NapiEnv) -> NapiResult<Self> {
+        NapiSymbol::well_known(env, "hasInstance")
+    }
+
+    pub fn to_primitive(env: &'env NapiEnv) -> NapiResult<Self> {
+        NapiSymbol::well_known(env, "toPrimitive")
+    }
+
+    pub fn to_string_tag(env: &'env NapiEnv) -> NapiResult<Self> {
+        NapiSymbol::well_known(env, "toStringTag")
+    }
+
+    pub fn dispose(env: &'env NapiEnv) -> NapiResult<Self> {
+        NapiSymbol::well_known(env, "dispose")
+    }
+
+    pub fn async_dispose(env: &'env NapiEnv) -> NapiResult<Self> {
+        NapiSymbol::well_known(env, "asyncDispose")
+    }
+
+    fn well_known(env: &'env NapiEnv, name: &str) -> NapiResult<Self> {
+        NapiObject::global(env)?
+            .get_named_property("Symbol")?
+            .to_napi_object()?
+            .get_named_property(name)?
+            .as_symbol()
+    }
+
+    pub fn description(&self) -> NapiResult<Option<String>> {
+        let description =
+            self.as_napi_object().get_named_property("description")?;
+
+        match description.value_type()? {
+            NapiValueType::Undefined => Ok(None),
+            _ => description.as_string()?.to_string().map(Some),
+        }
+    }
+}
+
+impl<'env> NapiValue<'env> for NapiSymbol<'env> {
+    fn as_sys_value(&self) -> sys::napi_value {
+        self.value
+    }
+
+    fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    fn from_sys_checked(
+        env: &'env NapiEnv,
+        value: sys::napi_value,
+    ) -> NapiResult<Self> {
+        if NapiAny::with_value(env, value).value_type()?
+            != NapiValueType::Symbol
+        {
+            let message = NapiString::from_str(env, "Symbol expected")?;
+            return Err(NapiError::type_error(env, &message));
+        }
+
+        Ok(Self { env, value })
+    }
+}
+
+impl<'env> NapiValueInternal<'env> for NapiSymbol<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
+    }
+}
+
+impl<'env> AsNapiObject<'env> for NapiSymbol<'env> {}
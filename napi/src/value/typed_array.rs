@@ -0,0 +1,165 @@
+use std::ptr;
+
+use env::NapiEnv;
+use result::{NapiError, NapiErrorKind, NapiResult};
+use sys;
+
+use super::{AsNapiObject, NapiAny, NapiArrayBuffer, NapiBytes, NapiBytesMut,
+            NapiString, NapiValue, NapiValueInternal};
+
+#[derive(Debug)]
+pub struct NapiTypedArray<'env> {
+    value: sys::napi_value,
+    env: &'env NapiEnv,
+}
+
+impl<'env> NapiTypedArray<'env> {
+    // The number of elements.
+    pub fn len(&self) -> NapiResult<usize> {
+        self.info().map(|(_, len, _, _, _)| len)
+    }
+
+    pub fn is_empty(&self) -> NapiResult<bool> {
+        self.len().map(|len| len == 0)
+    }
+
+    // The number of bytes, computed from the number and type of elements
+    // rather than read from the `byteLength` property, which scripts can
+    // redefine.
+    pub fn byte_len(&self) -> NapiResult<usize> {
+        let (array_type, len, _, _, _) = self.info()?;
+
+        element_size(array_type)
+            .and_then(|size| len.checked_mul(size))
+            .ok_or_else(|| NapiError {
+                kind: NapiErrorKind::GenericFailure,
+                message: Some(format!(
+                    "unknown typed array type {}",
+                    array_type,
+                )),
+                exception: None,
+                path: Vec::new(),
+            })
+    }
+
+    pub fn byte_offset(&self) -> NapiResult<usize> {
+        self.info().map(|(_, _, _, _, byte_offset)| byte_offset)
+    }
+
+    // The `napi_typedarray_type` of the array, as a code, since the runtime
+    // may know of more types than this crate.
+    pub(crate) fn array_type(
+        &self,
+    ) -> NapiResult<sys::napi_typedarray_type_code> {
+        self.info().map(|(array_type, _, _, _, _)| array_type)
+    }
+
+    pub fn arraybuffer(&self) -> NapiResult<NapiArrayBuffer<'env>> {
+        self.info().map(|(_, _, _, arraybuffer, _)| {
+            NapiArrayBuffer::construct(self.env, arraybuffer)
+        })
+    }
+
+    pub fn bytes(&self) -> NapiResult<NapiBytes<'_>> {
+        let (data, len) = self.raw_data()?;
+        unsafe { NapiBytes::borrow(self.env, data, len) }
+    }
+
+    pub fn bytes_mut(&mut self) -> NapiResult<NapiBytesMut<'_>> {
+        let (data, len) = self.raw_data()?;
+        unsafe { NapiBytesMut::borrow(self.env, data, len) }
+    }
+
+    fn raw_data(&self) -> NapiResult<(*mut u8, usize)> {
+        let (_, _, data, arraybuffer, _) = self.info()?;
+        NapiArrayBuffer::construct(self.env, arraybuffer).ensure_attached()?;
+
+        Ok((data, self.byte_len()?))
+    }
+
+    fn info(
+        &self,
+    ) -> NapiResult<(
+        sys::napi_typedarray_type_code,
+        usize,
+        *mut u8,
+        sys::napi_value,
+        usize,
+    )> {
+        let mut array_type = 0;
+        let mut len = 0;
+        let mut data = ptr::null_mut();
+        let mut arraybuffer = ptr::null_mut();
+        let mut byte_offset = 0;
+
+        self.env.handle_status(unsafe {
+            sys::napi_get_typedarray_info_code(
+                self.env.as_sys_env(),
+                self.value,
+                &mut array_type,
+                &mut len,
+                &mut data,
+                &mut arraybuffer,
+                &mut byte_offset,
+            )
+        })?;
+
+        Ok((array_type, len, data as *mut u8, arraybuffer, byte_offset))
+    }
+}
+
+// The size in bytes of the elements of typed arrays of the given type.
+fn element_size(array_type: sys::napi_typedarray_type_code) -> Option<usize> {
+    use sys::napi_typedarray_type::*;
+
+    const INT8: sys::napi_typedarray_type_code = napi_int8_array as _;
+    const UINT8: sys::napi_typedarray_type_code = napi_uint8_array as _;
+    const UINT8_CLAMPED: sys::napi_typedarray_type_code =
+        napi_uint8_clamped_array as _;
+    const INT16: sys::napi_typedarray_type_code = napi_int16_array as _;
+    const UINT16: sys::napi_typedarray_type_code = napi_uint16_array as _;
+    const INT32: sys::napi_typedarray_type_code = napi_int32_array as _;
+    const UINT32: sys::napi_typedarray_type_code = napi_uint32_array as _;
+    const FLOAT32: sys::napi_typedarray_type_code = napi_float32_array as _;
+    const FLOAT64: sys::napi_typedarray_type_code = napi_float64_array as _;
+
+    match array_type {
+        INT8 | UINT8 | UINT8_CLAMPED => Some(1),
+        INT16 | UINT16 => Some(2),
+        INT32 | UINT32 | FLOAT32 => Some(4),
+        FLOAT64 | sys::napi_bigint64_array | sys::napi_biguint64_array => {
+            Some(8)
+        }
+        _ => None,
+    }
+}
+
+impl<'env> NapiValue<'env> for NapiTypedArray<'env> {
+    fn as_sys_value(&self) -> sys::napi_value {
+        self.value
+    }
+
+    fn env(&self) -> &'env NapiEnv {
+        self.env
+    }
+
+    fn from_sys_checked(
+        env: &'env NapiEnv,
+        value: sys::napi_value,
+    ) -> NapiResult<Self> {
+        if !NapiAny::with_value(env, value).is_typedarray()? {
+            let message = NapiString::from_str(env, "TypedArray expected")?;
+            return Err(NapiError::type_error(env, &message));
+        }
+
+        Ok(Self { env, value })
+    }
+}
+
+impl<'env> NapiValueInternal<'env> for NapiTypedArray<'env> {
+    fn construct(env: &'env NapiEnv, value: sys::napi_value) -> Self {
+        Self { env, value }
+    }
+}
+
+impl<'env> AsNapiObject<'env> for NapiTypedArray<'env> {}
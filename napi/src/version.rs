@@ -0,0 +1,123 @@
+use std::fmt;
+use std::fmt::Display;
+
+#[cfg(feature = "dynamic-symbols")]
+use sys;
+
+// The version of Node.js that runs the addon.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NapiNodeVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+    // The name of the release, which is `"node"` for Node.js itself.
+    pub release: String,
+}
+
+impl Display for NapiNodeVersion {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "v{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+// What the runtime supports of the APIs wrapped by this crate, for addons
+// built with a higher `napiN` feature than some of the runtimes they load in
+// to fall back on something else instead of failing with `InvalidArg` or
+// `GenericFailure` errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NapiCapabilities {
+    napi_version: u32,
+    // Whether the functions of a capability must also be found in the
+    // process, as with `dynamic-symbols`, since runtimes may lack some of
+    // those of the version they report.
+    lookup: bool,
+}
+
+impl NapiCapabilities {
+    pub fn new(napi_version: u32) -> Self {
+        Self {
+            napi_version,
+            lookup: false,
+        }
+    }
+
+    // The capabilities of the runtime that runs the addon.
+    pub(crate) fn of_runtime(napi_version: u32) -> Self {
+        Self {
+            napi_version,
+            lookup: cfg!(feature = "dynamic-symbols"),
+        }
+    }
+
+    pub fn napi_version(&self) -> u32 {
+        self.napi_version
+    }
+
+    pub fn supports(&self, napi_version: u32) -> bool {
+        self.napi_version >= napi_version
+    }
+
+    pub fn dates(&self) -> bool {
+        self.provides(
+            5,
+            &["napi_create_date", "napi_is_date", "napi_get_date_value"],
+        )
+    }
+
+    // `NapiObject::add_finalizer`.
+    pub fn finalizers(&self) -> bool {
+        self.provides(5, &["napi_add_finalizer"])
+    }
+
+    // Creating and reading `NapiBigInt`s, and so `i128` and `u128`.
+    pub fn big_ints(&self) -> bool {
+        self.provides(
+            6,
+            &[
+                "napi_create_bigint_int64",
+                "napi_create_bigint_uint64",
+                "napi_create_bigint_words",
+                "napi_get_value_bigint_int64",
+                "napi_get_value_bigint_uint64",
+                "napi_get_value_bigint_words",
+            ],
+        )
+    }
+
+    pub fn instance_data(&self) -> bool {
+        self.provides(
+            6,
+            &["napi_set_instance_data", "napi_get_instance_data"],
+        )
+    }
+
+    pub fn detach(&self) -> bool {
+        self.provides(
+            7,
+            &["napi_is_detached_arraybuffer", "napi_detach_arraybuffer"],
+        )
+    }
+
+    pub fn type_tags(&self) -> bool {
+        self.provides(
+            8,
+            &["napi_type_tag_object", "napi_check_object_type_tag"],
+        )
+    }
+
+    // `NapiSymbol::for_key`.
+    pub fn symbol_for(&self) -> bool {
+        self.provides(9, &["node_api_symbol_for"])
+    }
+
+    #[cfg(feature = "dynamic-symbols")]
+    fn provides(&self, napi_version: u32, functions: &[&str]) -> bool {
+        self.supports(napi_version)
+            && (!self.lookup || functions.iter().all(|f| sys::has_function(f)))
+    }
+
+    #[cfg(not(feature = "dynamic-symbols"))]
+    fn provides(&self, napi_version: u32, _: &[&str]) -> bool {
+        self.supports(napi_version)
+    }
+}